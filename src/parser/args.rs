@@ -2,7 +2,8 @@ use crate::tokenizer::{Keywords, Token};
 
 use super::{
     error,
-    r#type::{Generic, Type},
+    r#do::Binding,
+    r#type::{Generic, GenericParams, Type},
     Parser, ParserError, ParserErrorStack, Error,
 };
 
@@ -54,10 +55,21 @@ impl TryFrom<&mut Parser> for Arg {
 
 #[derive(Debug)]
 pub struct ArgsTyped {
-    lifetimes: Vec<String>,
-    generics: Vec<Generic>,
+    params: GenericParams,
     selft: Option<Type>,
     args: Vec<(String, Type)>,
+    /// (fmt->*const c_char ..), only for foreign functions
+    variadic: bool,
+}
+
+impl ArgsTyped {
+    pub(crate) fn is_variadic(&self) -> bool {
+        self.variadic
+    }
+
+    pub(crate) fn has_self(&self) -> bool {
+        self.selft.is_some()
+    }
 }
 
 impl TryFrom<&mut Parser> for ArgsTyped {
@@ -65,40 +77,30 @@ impl TryFrom<&mut Parser> for ArgsTyped {
 
     fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
         let mut args = vec![];
-        let mut generics = vec![];
-        let mut lifetimes = vec![];
 
         let _ = error!("ArgsTyped", value.pop_front(), [Token::ParenOpen])?;
-
-        loop {
-            let peek = value.first_err("ArgsTyped")?;
-
-            match peek {
-                Token::Char(':') => {
-                    generics.push(error!(Generic::try_from(&mut *value), "ArgsTyped")?)
-                }
-                Token::BackTick => {
-                    value.pop_front();
-                    lifetimes.push(error!("ArgsTyped", value))
-                }
-                _ => break,
-            }
-        }
-
+        let params = error!(GenericParams::try_from(&mut *value), "ArgsTyped")?;
         let mut selft = None;
+        let mut variadic = false;
 
         loop {
             if value.first() == Some(&Token::ParenClose) {
                 value.pop_front();
                 break;
             }
+            if value.first() == Some(&Token::DoubleDot) {
+                value.pop_front();
+                let _ = error!("ArgsTyped", value.pop_front(), [Token::ParenClose])?;
+                variadic = true;
+                break;
+            }
             let arg = error!(Arg::try_from(&mut *value), "ArgsTyped")?;
             match arg {
                 Arg::Generic(_) => return Err(error!("ArgsTyped", Error::Other(format!("Expected a named arg, got a generic, those should be defined before named args")))),
-                Arg::Simple(_) => {
+                Arg::Simple(name) => {
                     return Err(error!(
                         "ArgsTyped",
-                        Error::Other(format!("Expected named arg, got a simple arg")),
+                        Error::Other(format!("Expected named arg, got a simple arg {name:?}")),
                     ))
                 }
                 Arg::SelfA(selft2) => {
@@ -109,10 +111,10 @@ impl TryFrom<&mut Parser> for ArgsTyped {
         }
 
         Ok(ArgsTyped {
-            lifetimes,
+            params,
             selft,
-            generics,
             args,
+            variadic,
         })
     }
 }
@@ -120,31 +122,8 @@ impl TryFrom<&mut Parser> for ArgsTyped {
 impl ToString for ArgsTyped {
     fn to_string(&self) -> String {
         format!(
-            "{}({}{})",
-            if !self.generics.is_empty() || !self.lifetimes.is_empty() {
-                format!("<{}>", {
-                    let lifetimes = self
-                        .lifetimes
-                        .iter()
-                        .map(|lifetime| format!("'{lifetime}"))
-                        .collect::<Vec<String>>()
-                        .join(", ");
-                    let generics = self
-                        .generics
-                        .iter()
-                        .map(|generic| generic.to_string())
-                        .collect::<Vec<String>>()
-                        .join(", ");
-
-                    if lifetimes.is_empty() {
-                        generics
-                    } else {
-                        lifetimes + ", " + &generics
-                    }
-                })
-            } else {
-                format!("")
-            },
+            "{}({}{}{})",
+            self.params.to_string(),
             if let Some(selft) = &self.selft {
                 format!(
                     "{}{}",
@@ -165,13 +144,19 @@ impl ToString for ArgsTyped {
                     .to_string()
             } else {
                 format!("")
+            },
+            match (self.variadic, self.args.is_empty()) {
+                (false, _) => String::new(),
+                (true, true) => "...".to_string(),
+                (true, false) => ", ...".to_string(),
             }
         )
     }
 }
 
+/// (x y->i32 <a b>-><u8 u8> (Point { x y })->Point)
 #[derive(Debug)]
-pub struct Args(Vec<String>);
+pub struct Args(Vec<Binding>);
 
 impl TryFrom<&mut Parser> for Args {
     type Error = ParserError;
@@ -186,11 +171,7 @@ impl TryFrom<&mut Parser> for Args {
                 break;
             }
 
-            let arg = error!(Arg::try_from(&mut *value), "Args")?;
-            match arg {
-                Arg::Simple(name) => args.push(name),
-                _ => return Err(error!("Args arg", Error::Other(format!("Expected just simple args")))),
-            }
+            args.push(error!(Binding::try_from(&mut *value), "Args")?);
         }
 
         Ok(Self(args))
@@ -201,10 +182,11 @@ impl ToString for Args {
     fn to_string(&self) -> String {
         format!(
             "|{}|",
-            &self
-                .0
+            self.0
                 .iter()
-                .fold(String::new(), |str, arg| { format!("{str}, {arg}") })[2..]
+                .map(|arg| arg.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
         )
     }
 }
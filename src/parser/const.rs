@@ -0,0 +1,169 @@
+use crate::tokenizer::{Keywords, Token};
+
+use super::{
+    defun::Scope, error, exp::Exp, r#type::Type, Error, Parser, ParserError, ParserErrorStack,
+};
+
+#[derive(Debug)]
+pub enum Const {
+    /// (pub const MAX->usize 100)
+    /// Inside of a trait the value can be left out: (const MAX->usize)
+    Const {
+        scope: Scope,
+        name: String,
+        r#type: Type,
+        value: Option<Exp>,
+    },
+    /// (static mut COUNTER->u32 0)
+    /// Inside of an extern block there is no value: (static errno->i32)
+    Static {
+        scope: Scope,
+        r#mut: bool,
+        name: String,
+        r#type: Type,
+        value: Option<Exp>,
+    },
+}
+
+impl TryFrom<&mut Parser> for Const {
+    type Error = ParserError;
+
+    fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
+        Self::parse(value, Place::Item)
+    }
+}
+
+/// Where a const or static is declared, which decides if it needs a value
+#[derive(PartialEq)]
+enum Place {
+    Item,
+    Trait,
+    Extern,
+}
+
+impl Const {
+    /// A const declared in a trait, where the value can be left out
+    pub(crate) fn associated(value: &mut Parser) -> Result<Self, ParserError> {
+        Self::parse(value, Place::Trait)
+    }
+
+    /// A static declared in an extern block, which never has a value
+    pub(crate) fn foreign(value: &mut Parser) -> Result<Self, ParserError> {
+        Self::parse(value, Place::Extern)
+    }
+
+    fn parse(value: &mut Parser, place: Place) -> Result<Self, ParserError> {
+        let _ = error!("Const", value.pop_front(), [Token::ParenOpen])?;
+        let scope = error!(Scope::try_from(&mut *value), "Const")?;
+        let kind = error!("Const", value);
+
+        if &kind != "const" && &kind != "static" {
+            return Err(error!(
+                "Const",
+                Error::Other(format!("Expected const or static, got {kind:?}"))
+            ));
+        }
+
+        let r#mut = if value.first() == Some(&Token::Keyword(Keywords::Mut)) {
+            value.pop_front();
+            true
+        } else {
+            false
+        };
+
+        if r#mut && &kind == "const" {
+            return Err(error!(
+                "Const",
+                Error::Other("Only statics can be mutable".to_string())
+            ));
+        }
+
+        let name = error!("Const", value);
+        let _ = error!("Const", value.pop_front(), [Token::Keyword(Keywords::LeftArrow)])?;
+        let r#type = error!(Type::try_from(&mut *value), "Const")?;
+        let exp = if value.first() == Some(&Token::ParenClose) {
+            None
+        } else {
+            Some(error!(Exp::try_from(&mut *value), "Const")?)
+        };
+        let _ = error!("Const", value.pop_front(), [Token::ParenClose])?;
+
+        let err = match (&place, &kind[..], &exp) {
+            (Place::Extern, "const", _) => {
+                Some(format!("Extern blocks can't hold consts, {name} should be a static"))
+            }
+            (Place::Extern, _, Some(_)) => Some(format!("Foreign static {name} can't have a value")),
+            (Place::Trait, "static", _) => {
+                Some(format!("Traits can't hold statics, {name} should be a const"))
+            }
+            (Place::Item, "const", None) => Some(format!("Const {name} needs a value")),
+            (Place::Item, _, None) => Some(format!("Static {name} needs a value")),
+            _ => None,
+        };
+
+        if let Some(err) = err {
+            return Err(error!("Const", Error::Other(err)));
+        }
+
+        if &kind == "const" {
+            return Ok(Self::Const {
+                scope,
+                name,
+                r#type,
+                value: exp,
+            });
+        }
+
+        Ok(Self::Static {
+            scope,
+            r#mut,
+            name,
+            r#type,
+            value: exp,
+        })
+    }
+}
+
+impl ToString for Const {
+    fn to_string(&self) -> String {
+        match self {
+            Self::Const {
+                scope,
+                name,
+                r#type,
+                value: Some(value),
+            } => format!(
+                "{}const {name}: {} = {};",
+                scope.to_string(),
+                r#type.to_string(),
+                value.to_string()
+            ),
+            Self::Const {
+                scope,
+                name,
+                r#type,
+                value: None,
+            } => format!("{}const {name}: {};", scope.to_string(), r#type.to_string()),
+            Self::Static {
+                scope,
+                r#mut,
+                name,
+                r#type,
+                value,
+            } => format!(
+                "{}static {}{name}: {}{};",
+                scope.to_string(),
+                if *r#mut {
+                    "mut ".to_string()
+                } else {
+                    String::new()
+                },
+                r#type.to_string(),
+                match value {
+                    Some(value) => format!(" = {}", value.to_string()),
+                    None => String::new(),
+                }
+            ),
+        }
+    }
+}
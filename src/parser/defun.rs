@@ -1,23 +1,27 @@
-use crate::tokenizer::{Keywords, Token};
+use crate::tokenizer::{Keywords, Literals, Token};
 
 use super::{
-    args::ArgsTyped, error, exp::Exp, r#type::Type, Parser, ParserError, ParserErrorStack, Error,
+    args::ArgsTyped, error, exp::Exp, r#type::{Type, Where}, Parser, ParserError, ParserErrorStack, Error,
 };
 
 #[derive(Debug)]
 pub enum Defun {
     Function {
         scope: Scope,
+        qualifiers: Qualifiers,
         name: String,
         args: ArgsTyped,
         return_type: Type,
+        r#where: Where,
         body: Exp,
     },
     Header {
         scope: Scope,
+        qualifiers: Qualifiers,
         name: String,
         args: ArgsTyped,
         return_type: Type,
+        r#where: Where,
     },
 }
 
@@ -25,21 +29,52 @@ impl TryFrom<&mut Parser> for Defun {
     type Error = ParserError;
 
     fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
+        Self::parse(value, false)
+    }
+}
+
+impl Defun {
+    /// A function declared in an extern block, a header that can be variadic
+    pub(crate) fn foreign(value: &mut Parser) -> Result<Self, ParserError> {
+        Self::parse(value, true)
+    }
+
+    fn parse(value: &mut Parser, foreign: bool) -> Result<Self, ParserError> {
         let _ = error!("Defun", value.pop_front(), [Token::ParenOpen])?;
-        let scope = Scope::try_from(&mut *value).unwrap();
+        let scope = error!(Scope::try_from(&mut *value), "Defun")?;
+        let qualifiers = error!(Qualifiers::try_from(&mut *value), "Defun")?;
         let _ = error!("Defun", value.pop_front(), [Token::Keyword(Keywords::Defun)])?;
         let name = error!("Defun", value);
         let args = error!(ArgsTyped::try_from(&mut *value), "Defun")?;
         let _ = error!("Defun", value.pop_front(), [Token::Keyword(Keywords::LeftArrow)])?;
         let return_type = error!(Type::try_from(&mut *value), "Defun")?;
+        let r#where = error!(Where::try_from(&mut *value), "Defun")?;
+
+        let err = if foreign && !qualifiers.is_empty() {
+            Some(format!("Foreign function {name} can't be async, unsafe or extern"))
+        } else if foreign && args.has_self() {
+            Some(format!("Foreign function {name} can't take self"))
+        } else if foreign && value.first() != Some(&Token::ParenClose) {
+            Some(format!("Foreign function {name} can't have a body"))
+        } else if !foreign && args.is_variadic() {
+            Some(format!("Only functions in extern blocks can be variadic, got {name}"))
+        } else {
+            None
+        };
+
+        if let Some(err) = err {
+            return Err(error!("Defun", Error::Other(err)));
+        }
 
         if value.first() == Some(&Token::ParenClose) {
             value.pop_front();
             return Ok(Self::Header {
                 scope,
+                qualifiers,
                 name,
                 args,
                 return_type,
+                r#where,
             });
         }
 
@@ -48,9 +83,11 @@ impl TryFrom<&mut Parser> for Defun {
 
         Ok(Defun::Function {
             scope,
+            qualifiers,
             name,
             args,
             return_type,
+            r#where,
             body,
         })
     }
@@ -61,60 +98,123 @@ impl ToString for Defun {
         match self {
             Self::Function {
                 scope,
+                qualifiers,
                 name,
                 args,
                 return_type,
+                r#where,
                 body,
             } => {
                 format!(
-                    "{}fn {}{} -> {} {{{}}}",
+                    "{}{}fn {}{} -> {}{} {{{}}}",
                     scope.to_string(),
+                    qualifiers.to_string(),
                     name,
                     args.to_string(),
                     return_type.to_string(),
+                    r#where.to_string(),
                     body.to_string()
                 )
             }
             Self::Header {
                 scope,
+                qualifiers,
                 name,
                 args,
                 return_type,
+                r#where,
             } => {
                 format!(
-                    "{}fn {}{} -> {};",
+                    "{}{}fn {}{} -> {}{};",
                     scope.to_string(),
+                    qualifiers.to_string(),
                     name,
                     args.to_string(),
                     return_type.to_string(),
+                    r#where.to_string(),
                 )
             }
         }
     }
 }
 
+/// crate, pub, pub(crate), pub(super), pub(in crate->parser)
 #[derive(Debug)]
 pub enum Scope {
     File,
     Crate,
+    Super,
+    In(Vec<String>),
     Full,
 }
 
+impl Scope {
+    /// Index of the first token after a scope starting at `nth`, used to look ahead at the item
+    pub(crate) fn skip(value: &Parser, nth: usize) -> usize {
+        match value.nth(nth) {
+            Some(Token::Identifier(iden))
+                if iden == "crate" && value.nth(nth + 1) != Some(&Token::Keyword(Keywords::LeftArrow)) =>
+            {
+                nth + 1
+            }
+            Some(Token::Identifier(iden)) if iden == "pub" && Self::is_restricted(value, nth + 1) => {
+                let mut nth = nth + 1;
+
+                while value.nth(nth).is_some_and(|token| token != &Token::ParenClose) {
+                    nth += 1;
+                }
+
+                nth + 1
+            }
+            Some(Token::Identifier(iden)) if iden == "pub" => nth + 1,
+            _ => nth,
+        }
+    }
+
+    fn is_restricted(value: &Parser, nth: usize) -> bool {
+        value.nth(nth) == Some(&Token::ParenOpen)
+            && matches!(value.nth(nth + 1), Some(Token::Identifier(iden)) if iden == "crate" || iden == "super" || iden == "in")
+    }
+}
+
 impl TryFrom<&mut Parser> for Scope {
     type Error = ParserError;
 
     fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
         match value.first_err("Scope")? {
             Token::Identifier(iden) => match &iden[..] {
-                "crate" => {
+                "crate" if value.nth(1) != Some(&Token::Keyword(Keywords::LeftArrow)) => {
                     value.pop_front();
                     Ok(Scope::Crate)
                 }
+                "pub" if Self::is_restricted(value, 1) => {
+                    value.pop_front();
+                    value.pop_front();
+
+                    let scope = match &error!("Scope", value)[..] {
+                        "crate" => Scope::Crate,
+                        "super" => Scope::Super,
+                        "in" => {
+                            let mut path = vec![error!("Scope", value)];
+
+                            while value.first() == Some(&Token::Keyword(Keywords::LeftArrow)) {
+                                value.pop_front();
+                                path.push(error!("Scope", value));
+                            }
+
+                            Scope::In(path)
+                        }
+                        _ => unreachable!(),
+                    };
+                    let _ = error!("Scope", value.pop_front(), [Token::ParenClose])?;
+
+                    Ok(scope)
+                }
                 "pub" => {
                     value.pop_front();
                     Ok(Scope::Full)
                 }
-                iden => Err(error!("Scope iden", Error::Other(format!("Expected pub or crate, got {iden:#?}")))),
+                _ => Ok(Scope::File),
             },
             _ => Ok(Scope::File),
         }
@@ -124,10 +224,110 @@ impl TryFrom<&mut Parser> for Scope {
 impl ToString for Scope {
     fn to_string(&self) -> String {
         match self {
-            Scope::File => "",
-            Scope::Crate => "pub(crate) ",
-            Scope::Full => "pub ",
+            Scope::File => String::new(),
+            Scope::Crate => "pub(crate) ".to_string(),
+            Scope::Super => "pub(super) ".to_string(),
+            Scope::In(path) => format!("pub(in {}) ", path.join("::")),
+            Scope::Full => "pub ".to_string(),
+        }
+    }
+}
+
+/// (pub async unsafe defun fetch (url->&str)->string ...)
+/// (pub extern "C" defun callback (x->i32)->i32 ...)
+#[derive(Debug)]
+pub struct Qualifiers {
+    r#async: bool,
+    r#unsafe: bool,
+    abi: Option<String>,
+}
+
+impl Qualifiers {
+    /// Identifiers that can stand between the scope and the item keyword
+    const KEYWORDS: [&'static str; 2] = ["async", "unsafe"];
+
+    pub(crate) fn is_empty(&self) -> bool {
+        !self.r#async && !self.r#unsafe && self.abi.is_none()
+    }
+
+    /// Index of the first token after the qualifiers starting at `nth`, used to look ahead at the item
+    pub(crate) fn skip(value: &Parser, mut nth: usize) -> usize {
+        loop {
+            match value.nth(nth) {
+                Some(Token::Identifier(iden)) if Self::KEYWORDS.contains(&&iden[..]) => nth += 1,
+                Some(Token::Identifier(iden))
+                    if iden == "extern" && matches!(value.nth(nth + 1), Some(Token::Literal(Literals::String(_)))) =>
+                {
+                    nth += 2
+                }
+                _ => break nth,
+            }
+        }
+    }
+}
+
+impl TryFrom<&mut Parser> for Qualifiers {
+    type Error = ParserError;
+
+    fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
+        let mut r#async = false;
+        let mut r#unsafe = false;
+        let mut abi = None;
+
+        loop {
+            let qualifier = match value.first_err("Qualifiers")? {
+                Token::Identifier(iden) if iden == "async" => &mut r#async,
+                Token::Identifier(iden) if iden == "unsafe" => &mut r#unsafe,
+                Token::Identifier(iden) if iden == "extern" => {
+                    if abi.is_some() {
+                        return Err(error!(
+                            "Qualifiers",
+                            Error::Other(format!("Duplicate qualifier {:?}", value.first()))
+                        ));
+                    }
+
+                    value.pop_front();
+                    abi = match error!("Qualifiers", value.pop_front(), [Token::Literal(Literals::String(_))])? {
+                        Token::Literal(Literals::String(abi)) => Some(abi),
+                        _ => unreachable!(),
+                    };
+                    continue;
+                }
+                _ => break Ok(Self { r#async, r#unsafe, abi }),
+            };
+
+            if *qualifier {
+                return Err(error!(
+                    "Qualifiers",
+                    Error::Other(format!("Duplicate qualifier {:?}", value.first()))
+                ));
+            }
+
+            value.pop_front();
+            *qualifier = true;
         }
-        .to_string()
+    }
+}
+
+impl ToString for Qualifiers {
+    fn to_string(&self) -> String {
+        format!(
+            "{}{}{}",
+            if self.r#async {
+                "async ".to_string()
+            } else {
+                String::new()
+            },
+            if self.r#unsafe {
+                "unsafe ".to_string()
+            } else {
+                String::new()
+            },
+            if let Some(abi) = &self.abi {
+                format!("extern \"{abi}\" ")
+            } else {
+                String::new()
+            }
+        )
     }
 }
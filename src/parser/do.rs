@@ -4,8 +4,11 @@ use super::{
     attribute::Attribute,
     error,
     exp::Exp,
-    r#type::{NamespacedType, TypeAlias},
-    Parser, ParserError, ParserErrorStack, r#use::Use,
+    r#if::Condition,
+    r#match::PatternBinding,
+    path::Path,
+    r#type::{Type, TypeAlias},
+    Error, Parser, ParserError, ParserErrorStack, r#use::Use,
 };
 
 #[derive(Debug)]
@@ -17,6 +20,15 @@ impl TryFrom<&mut Parser> for Do {
     fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
         let _ = error!("Do", value.pop_front(), [Token::ParenOpen])?;
         let _ = error!("Do", value.pop_front(), [Token::Keyword(Keywords::Do)])?;
+
+        Self::body(value)
+    }
+}
+
+impl Do {
+    /// Parses actions up to and including the closing paren, for forms
+    /// that wrap a block like `(async ...)`
+    pub(crate) fn body(value: &mut Parser) -> Result<Self, ParserError> {
         let mut actions = vec![];
 
         loop {
@@ -32,6 +44,52 @@ impl TryFrom<&mut Parser> for Do {
     }
 }
 
+impl Do {
+    /// (when (> n 0) (println! "{}" n) (tick))
+    /// (unless done (retry))
+    ///
+    /// An if without an else, so the body is turned into statements
+    pub(crate) fn when(value: &mut Parser) -> Result<Self, ParserError> {
+        let _ = error!("When", value.pop_front(), [Token::ParenOpen])?;
+        let unless = match error!("When", value) {
+            iden if iden == "when" => false,
+            iden if iden == "unless" => true,
+            iden => {
+                return Err(error!(
+                    "When",
+                    Error::Other(format!("Expected when or unless, got {iden:?}"))
+                ))
+            }
+        };
+        let condition = match error!(Condition::try_from(&mut *value), "When")? {
+            Condition::Bool(exp) if unless => Condition::Bool(Exp::Not(Box::new(exp))),
+            Condition::Let(..) if unless => {
+                return Err(error!(
+                    "When",
+                    Error::Other("unless can't take a let condition".to_string())
+                ))
+            }
+            condition => condition,
+        };
+        let body = error!(Self::body(&mut *value), "When")?;
+
+        Ok(Self(vec![DoActions::If {
+            condition,
+            true_branch: Block(
+                body.0
+                    .into_iter()
+                    .map(|action| match action {
+                        DoActions::Ret(exp) => DoActions::Semicolon(exp),
+                        action => action,
+                    })
+                    .collect(),
+            ),
+            elif_branch: vec![],
+            false_branch: None,
+        }]))
+    }
+}
+
 impl ToString for Do {
     fn to_string(&self) -> String {
         format!(
@@ -47,38 +105,55 @@ impl ToString for Do {
 pub enum DoActions {
     /// (do let
     ///     StructType { field1 field2->varname field3-><_ var2> .. }
-    ///     <- (func arg1 arg2))
-    Let(bool, Destructuring, Exp),
-    Assignment(String, Exp),
+    ///     <- (func arg1 arg2)
+    ///     let mut count->u64 <- 0)
+    Let(Binding, Exp),
+    /// (do
+    ///     self/count <- 0
+    ///     *ptr <- (idx buf i))
+    Assignment(Exp, Exp),
+    /// (do
+    ///     self/count += 1
+    ///     (idx flags i) |= mask)
+    CompoundAssignment(Exp, AssignOp, Exp),
     /// (do if true
     ///         break
+    ///     elif let (Option->Some x) <- (stack.pop)
+    ///         x
     ///     elif (= x 10)
     ///         continue)
     If {
-        condition: Exp,
-        true_branch: Box<DoActions>,
-        elif_branch: Vec<(Exp, DoActions)>,
-        false_branch: Option<Box<DoActions>>,
+        condition: Condition,
+        true_branch: Block,
+        elif_branch: Vec<(Condition, Block)>,
+        false_branch: Option<Block>,
     },
     /// (do
-    ///     for i, val <- (variable.iter)
-    ///         (println! "{}: {}" i val))
+    ///     'rows for <i row> <- ((grid.iter).enumerate)
+    ///         (println! "{}: {:?}" i row))
     For {
+        label: Option<String>,
         vals: Destructuring,
         iter: Exp,
-        body: Box<DoActions>,
+        body: Block,
     },
     /// (do loop
     ///     (if true break continue))
-    Loop(Box<DoActions>),
-    While(Exp, Box<DoActions>),
+    Loop(Option<String>, Block),
+    /// (do
+    ///     while let (Option->Some item) <- (stack.pop)
+    ///         (println! "{}" item))
+    While(Option<String>, Condition, Block),
     TypeAlias(TypeAlias),
     Attribute(Attribute),
     Use(Use),
     Ret(Exp),
     Semicolon(Exp),
-    Break,
-    Continue,
+    /// (do 'outer loop
+    ///     loop
+    ///         break 'outer)
+    Break(Option<String>),
+    Continue(Option<String>),
 }
 
 impl TryFrom<&mut Parser> for DoActions {
@@ -94,58 +169,26 @@ impl TryFrom<&mut Parser> for DoActions {
                 Token::Keyword(Keywords::Let) => {
                     value.pop_front();
 
-                    let mutable = if let Some(&Token::Keyword(Keywords::Mut)) = value.first() {
-                        value.pop_front();
-                        true
-                    } else {
-                        false
-                    };
-
-                    let pattern = error!(Destructuring::try_from(&mut *value), "DoActions")?;
+                    let binding = error!(Binding::try_from(&mut *value), "DoActions")?;
                     let _ = error!("DoActions", value.pop_front(), [Token::Keyword(Keywords::RightArrow)])?;
 
                     Self::Let(
-                        mutable,
-                        pattern,
+                        binding,
                         error!(Exp::try_from(&mut *value), "DoActions")?,
                     )
                 }
-                Token::Identifier(_) => {
-                    if value.nth(1) == Some(&Token::Keyword(Keywords::RightArrow)) {
-                        let var = error!("DoActions", value);
-                        value.pop_front();
-
-                        Self::Assignment(var, error!(Exp::try_from(&mut *value), "DoActions")?)
-                    } else {
-                        let exp = error!(Exp::try_from(&mut *value), "DoActions")?;
-
-                        if let Some(&Token::Char(';')) = value.first() {
-                            value.pop_front();
-                            Self::Semicolon(exp)
-                        } else {
-                            Self::Ret(exp)
-                        }
-                    }
-                }
                 Token::Keyword(Keywords::If) => {
                     value.pop_front();
-                    let condition = error!(Exp::try_from(&mut *value), "DoActions")?;
-                    let true_branch =
-                        Box::new(error!(DoActions::try_from(&mut *value), "DoActions")?);
+                    let condition = error!(Condition::try_from(&mut *value), "DoActions")?;
+                    let true_branch = error!(Block::try_from(&mut *value), "DoActions")?;
                     let mut elif_branch = vec![];
 
-                    loop {
-                        let peek = value.first();
-
-                        if peek == Some(&Token::Keyword(Keywords::Elif)) {
-                            value.pop_front();
-                            let cond = error!(Exp::try_from(&mut *value), "DoActions")?;
-                            let body = error!(DoActions::try_from(&mut *value), "DoActions")?;
-
-                            elif_branch.push((cond, body))
-                        }
+                    while value.first() == Some(&Token::Keyword(Keywords::Elif)) {
+                        value.pop_front();
+                        let cond = error!(Condition::try_from(&mut *value), "DoActions")?;
+                        let body = error!(Block::try_from(&mut *value), "DoActions")?;
 
-                        break;
+                        elif_branch.push((cond, body))
                     }
 
                     if let Some(&Token::Keyword(Keywords::Else)) = value.first() {
@@ -155,10 +198,10 @@ impl TryFrom<&mut Parser> for DoActions {
                             condition,
                             true_branch,
                             elif_branch,
-                            false_branch: Some(Box::new(error!(
-                                DoActions::try_from(&mut *value),
+                            false_branch: Some(error!(
+                                Block::try_from(&mut *value),
                                 "DoActions"
-                            )?)),
+                            )?),
                         }
                     } else {
                         Self::If {
@@ -174,23 +217,45 @@ impl TryFrom<&mut Parser> for DoActions {
                     let vals = error!(Destructuring::try_from(&mut *value), "DoActions")?;
                     let _ = error!("DoActions", value.pop_front(), [Token::Keyword(Keywords::RightArrow)])?;
                     let iter = error!(Exp::try_from(&mut *value), "DoActions")?;
-                    let body = Box::new(error!(DoActions::try_from(&mut *value), "DoActions")?);
+                    let body = error!(Block::try_from(&mut *value), "DoActions")?;
 
-                    Self::For { vals, iter, body }
+                    Self::For {
+                        label: None,
+                        vals,
+                        iter,
+                        body,
+                    }
                 }
                 Token::Keyword(Keywords::Loop) => {
                     value.pop_front();
-                    Self::Loop(Box::new(error!(
-                        DoActions::try_from(&mut *value),
-                        "DoActions"
-                    )?))
+                    Self::Loop(None, error!(Block::try_from(&mut *value), "DoActions")?)
                 }
                 Token::Keyword(Keywords::While) => {
                     value.pop_front();
-                    let cond = error!(Exp::try_from(&mut *value), "DoActions")?;
-                    let body = Box::new(error!(DoActions::try_from(&mut *value), "DoActions")?);
+                    let cond = error!(Condition::try_from(&mut *value), "DoActions")?;
+                    let body = error!(Block::try_from(&mut *value), "DoActions")?;
+
+                    Self::While(None, cond, body)
+                }
+                Token::Lifetime(_) => {
+                    let Some(Token::Lifetime(label)) = value.pop_front() else {
+                        unreachable!()
+                    };
+                    let _ = error!(
+                        "DoActions",
+                        value.first(),
+                        [Token::Keyword(Keywords::For | Keywords::Loop | Keywords::While)]
+                    )?;
+                    let mut action = error!(DoActions::try_from(&mut *value), "DoActions")?;
+
+                    match &mut action {
+                        Self::For { label: slot, .. } | Self::Loop(slot, _) | Self::While(slot, ..) => {
+                            *slot = Some(label)
+                        }
+                        _ => unreachable!(),
+                    }
 
-                    Self::While(cond, body)
+                    action
                 }
                 Token::Keyword(Keywords::Type) => {
                     Self::TypeAlias(error!(TypeAlias::try_from(&mut *value), "DoActions")?)
@@ -198,22 +263,58 @@ impl TryFrom<&mut Parser> for DoActions {
                 Token::Keyword(Keywords::Use) => {
                     Self::Use(error!(Use::try_from(&mut *value), "DoActions")?)
                 }
-                Token::Keyword(Keywords::Break) => {
-                    value.pop_front();
-                    Self::Break
-                }
-                Token::Keyword(Keywords::Continue) => {
-                    value.pop_front();
-                    Self::Continue
+                Token::Keyword(Keywords::Break | Keywords::Continue) => {
+                    let r#break = value.pop_front() == Some(Token::Keyword(Keywords::Break));
+                    let label = if let Some(&Token::Lifetime(_)) = value.first() {
+                        let Some(Token::Lifetime(label)) = value.pop_front() else {
+                            unreachable!()
+                        };
+
+                        Some(label)
+                    } else {
+                        None
+                    };
+
+                    if r#break {
+                        Self::Break(label)
+                    } else {
+                        Self::Continue(label)
+                    }
                 }
                 _ => {
                     let exp = error!(Exp::try_from(&mut *value), "DoActions")?;
 
-                    if let Some(&Token::Char(';')) = value.first() {
-                        value.pop_front();
-                        Self::Semicolon(exp)
-                    } else {
-                        Self::Ret(exp)
+                    if (value.first() == Some(&Token::Keyword(Keywords::RightArrow))
+                        || AssignOp::is_next(value))
+                        && !exp.is_place()
+                    {
+                        return Err(error!(
+                            "DoActions",
+                            Error::Other(format!(
+                                "Expected a variable, field, deref or an index to assign to, got {exp:#?}"
+                            ))
+                        ));
+                    }
+
+                    match value.first() {
+                        Some(&Token::Keyword(Keywords::RightArrow)) => {
+                            value.pop_front();
+                            Self::Assignment(exp, error!(Exp::try_from(&mut *value), "DoActions")?)
+                        }
+                        _ if AssignOp::is_next(value) => {
+                            let op = error!(AssignOp::try_from(&mut *value), "DoActions")?;
+
+                            Self::CompoundAssignment(
+                                exp,
+                                op,
+                                error!(Exp::try_from(&mut *value), "DoActions")?,
+                            )
+                        }
+                        Some(&Token::Char(';')) => {
+                            value.pop_front();
+                            Self::Semicolon(exp)
+                        }
+                        _ => Self::Ret(exp),
                     }
                 }
             },
@@ -224,19 +325,15 @@ impl TryFrom<&mut Parser> for DoActions {
 impl ToString for DoActions {
     fn to_string(&self) -> String {
         match self {
-            Self::Let(mutable, pattern, exp) => {
-                format!(
-                    "let{} {} = {};",
-                    if *mutable {
-                        format!(" mut")
-                    } else {
-                        format!("")
-                    },
-                    pattern.to_string(),
-                    exp.to_string()
-                )
+            Self::Let(binding, exp) => {
+                format!("let {} = {};", binding.to_string(), exp.to_string())
+            }
+            Self::Assignment(place, exp) => {
+                format!("{} = {};", place.to_string(), exp.to_string())
+            }
+            Self::CompoundAssignment(place, op, exp) => {
+                format!("{} {} {};", place.to_string(), op.to_string(), exp.to_string())
             }
-            Self::Assignment(var, exp) => format!("{var} = {};", exp.to_string()),
             Self::If {
                 condition,
                 true_branch,
@@ -244,55 +341,316 @@ impl ToString for DoActions {
                 false_branch,
             } => {
                 format!(
-                    "{{if {} {{{}}}{}{}}}",
+                    "if {} {}{}{}",
                     condition.to_string(),
                     true_branch.to_string(),
-                    &if elif_branch.is_empty() {
-                        format!(" else if ")
-                    } else {
-                        elif_branch.iter().fold(String::new(), |str, elif| {
-                            format!(
-                                "{str} else if {} {{{}}}",
-                                elif.0.to_string(),
-                                elif.1.to_string()
-                            )
-                        })
-                    }[9..],
+                    elif_branch.iter().fold(String::new(), |str, elif| {
+                        format!(
+                            "{str} else if {} {}",
+                            elif.0.to_string(),
+                            elif.1.to_string()
+                        )
+                    }),
                     match false_branch {
-                        Some(body) => format!(" else {{{}}}", body.to_string()),
+                        Some(body) => format!(" else {}", body.to_string()),
                         None => format!(""),
                     }
                 )
             }
-            Self::For { vals, iter, body } => {
+            Self::For {
+                label,
+                vals,
+                iter,
+                body,
+            } => {
                 format!(
-                    "{{for {} in {} {{{}}}}};",
+                    "{}for {} in {} {}",
+                    label_to_string(label),
                     vals.to_string(),
                     iter.to_string(),
                     body.to_string()
                 )
             }
-            Self::Loop(body) => format!("loop {{{}}}", body.to_string()),
-            Self::While(cond, body) => {
-                format!("while {} {{{}}}", cond.to_string(), body.to_string())
+            Self::Loop(label, body) => {
+                format!("{}loop {}", label_to_string(label), body.to_string())
+            }
+            Self::While(label, cond, body) => {
+                format!(
+                    "{}while {} {}",
+                    label_to_string(label),
+                    cond.to_string(),
+                    body.to_string()
+                )
             }
             Self::TypeAlias(type_alias) => type_alias.to_string(),
             Self::Attribute(attr) => attr.to_string(),
             Self::Use(r#use) => r#use.to_string(),
             Self::Ret(action) => action.to_string(),
             Self::Semicolon(exp) => format!("{};", exp.to_string()),
-            Self::Break => format!("break;"),
-            Self::Continue => format!("continue;"),
+            Self::Break(Some(label)) => format!("break '{label};"),
+            Self::Break(None) => "break;".to_string(),
+            Self::Continue(Some(label)) => format!("continue '{label};"),
+            Self::Continue(None) => "continue;".to_string(),
         }
     }
 }
 
+pub(crate) fn label_to_string(label: &Option<String>) -> String {
+    match label {
+        Some(label) => format!("'{label}: "),
+        None => String::new(),
+    }
+}
+
+/// Body of a loop or an if branch in a do block, either a single action
+/// or the actions of a do form
+///
+/// (do for x <- xs (do
+///     let y <- (* x 2)
+///     total += y))
+#[derive(Debug)]
+pub struct Block(Vec<DoActions>);
+
+impl TryFrom<&mut Parser> for Block {
+    type Error = ParserError;
+
+    fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
+        if value.first() != Some(&Token::ParenOpen)
+            || value.nth(1) != Some(&Token::Keyword(Keywords::Do))
+        {
+            return Ok(Self(vec![error!(DoActions::try_from(&mut *value), "Block")?]));
+        }
+
+        Ok(Self(error!(Do::try_from(&mut *value), "Block")?.0))
+    }
+}
+
+impl ToString for Block {
+    fn to_string(&self) -> String {
+        format!(
+            "{{{}}}",
+            self.0
+                .iter()
+                .map(|action| action.to_string())
+                .collect::<Vec<String>>()
+                .join("\n")
+        )
+    }
+}
+
+#[derive(Debug)]
+pub enum AssignOp {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo,
+    BitwiseAnd,
+    BitwiseOr,
+    Xor,
+    LeftShift,
+    RightShift,
+}
+
+impl AssignOp {
+    /// `+=`, `<<=`, ... are tokenized as their separate chars, so this checks
+    /// for the whole sequence ending with `=`
+    fn is_next(value: &Parser) -> bool {
+        match value.first() {
+            Some(
+                &Token::Char('+' | '-' | '%' | '|' | '^')
+                | &Token::Keyword(Keywords::Deref)
+                | &Token::Slash
+                | &Token::Ref,
+            ) => value.nth(1) == Some(&Token::Char('=')),
+            Some(&Token::AngleBracketOpen) => {
+                value.nth(1) == Some(&Token::AngleBracketOpen)
+                    && value.nth(2) == Some(&Token::Char('='))
+            }
+            Some(&Token::AngleBracketClose) => {
+                value.nth(1) == Some(&Token::AngleBracketClose)
+                    && value.nth(2) == Some(&Token::Char('='))
+            }
+            _ => false,
+        }
+    }
+}
+
+impl TryFrom<&mut Parser> for AssignOp {
+    type Error = ParserError;
+
+    fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
+        let op = match error!(
+            "AssignOp",
+            value.pop_front(),
+            [
+                Token::Char('+' | '-' | '%' | '|' | '^'),
+                Token::Keyword(Keywords::Deref),
+                Token::Slash,
+                Token::Ref,
+                Token::AngleBracketOpen,
+                Token::AngleBracketClose
+            ]
+        )? {
+            Token::Char('+') => Self::Add,
+            Token::Char('-') => Self::Subtract,
+            Token::Keyword(Keywords::Deref) => Self::Multiply,
+            Token::Slash => Self::Divide,
+            Token::Char('%') => Self::Modulo,
+            Token::Ref => Self::BitwiseAnd,
+            Token::Char('|') => Self::BitwiseOr,
+            Token::Char('^') => Self::Xor,
+            Token::AngleBracketOpen => {
+                let _ = error!("AssignOp", value.pop_front(), [Token::AngleBracketOpen])?;
+                Self::LeftShift
+            }
+            Token::AngleBracketClose => {
+                let _ = error!("AssignOp", value.pop_front(), [Token::AngleBracketClose])?;
+                Self::RightShift
+            }
+            _ => unreachable!(),
+        };
+        let _ = error!("AssignOp", value.pop_front(), [Token::Char('=')])?;
+
+        Ok(op)
+    }
+}
+
+impl ToString for AssignOp {
+    fn to_string(&self) -> String {
+        match self {
+            Self::Add => "+=",
+            Self::Subtract => "-=",
+            Self::Multiply => "*=",
+            Self::Divide => "/=",
+            Self::Modulo => "%=",
+            Self::BitwiseAnd => "&=",
+            Self::BitwiseOr => "|=",
+            Self::Xor => "^=",
+            Self::LeftShift => "<<=",
+            Self::RightShift => ">>=",
+        }
+        .to_string()
+    }
+}
+
+/// A destructuring pattern with an optional type, a plain name followed
+/// by `->` is a typed variable unless the path ends in a struct pattern,
+/// mut is only allowed on a plain name
+///
+/// mut x->u64
+/// <a b>-><i32 i32>
+/// shapes->Point { x y }
+#[derive(Debug)]
+pub struct Binding {
+    mutable: bool,
+    pattern: Destructuring,
+    r#type: Option<Type>,
+}
+
+impl TryFrom<&mut Parser> for Binding {
+    type Error = ParserError;
+
+    fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
+        let mutable = if value.first() == Some(&Token::Keyword(Keywords::Mut)) {
+            value.pop_front();
+            true
+        } else {
+            false
+        };
+
+        let pattern = if matches!(value.first(), Some(&Token::Identifier(_)))
+            && value.nth(1) == Some(&Token::Keyword(Keywords::LeftArrow))
+            && !Self::is_struct(value)
+        {
+            Destructuring::Variable(error!("Binding", value))
+        } else {
+            error!(Destructuring::try_from(&mut *value), "Binding")?
+        };
+
+        if mutable && !matches!(pattern, Destructuring::Variable(_)) {
+            return Err(error!(
+                "Binding",
+                Error::Other(format!(
+                    "Only a plain name can be mut, put mut on the names inside {}",
+                    pattern.to_string()
+                ))
+            ));
+        }
+
+        let r#type = if value.first() == Some(&Token::Keyword(Keywords::LeftArrow)) {
+            value.pop_front();
+            Some(error!(Type::try_from(&mut *value), "Binding")?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            mutable,
+            pattern,
+            r#type,
+        })
+    }
+}
+
+impl Binding {
+    /// A path made of names and generics that ends in the curly brace of a struct pattern
+    fn is_struct(value: &Parser) -> bool {
+        let mut nth = 0;
+
+        loop {
+            if !matches!(value.nth(nth), Some(Token::Identifier(_))) {
+                return false;
+            }
+            nth += 1;
+
+            if value.nth(nth) == Some(&Token::Keyword(Keywords::TurboStart)) {
+                let mut depth = 0;
+
+                loop {
+                    match value.nth(nth) {
+                        Some(Token::Keyword(Keywords::TurboStart) | Token::AngleBracketOpen) => depth += 1,
+                        Some(Token::AngleBracketClose) => depth -= 1,
+                        Some(_) => {}
+                        None => return false,
+                    }
+                    nth += 1;
+
+                    if depth == 0 {
+                        break;
+                    }
+                }
+            }
+
+            match value.nth(nth) {
+                Some(Token::Keyword(Keywords::LeftArrow)) => nth += 1,
+                next => return next == Some(&Token::CurlyOpen),
+            }
+        }
+    }
+}
+
+impl ToString for Binding {
+    fn to_string(&self) -> String {
+        format!(
+            "{}{}{}",
+            if self.mutable { "mut " } else { "" },
+            self.pattern.to_string(),
+            match &self.r#type {
+                Some(r#type) => format!(": {}", r#type.to_string()),
+                None => String::new(),
+            }
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum Destructuring {
     Touple(Vec<Self>),
     Array(Vec<Self>),
-    Struct(NamespacedType, Vec<LetStructField>),
+    Struct(Path, Vec<LetStructField>),
     Variable(String),
+    Binding(PatternBinding<Self>),
     Rest,
 }
 
@@ -300,15 +658,19 @@ impl TryFrom<&mut Parser> for Destructuring {
     type Error = ParserError;
 
     fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
+        if PatternBinding::<Self>::is_next(value) {
+            return Ok(Self::Binding(error!(PatternBinding::try_from(&mut *value), "LetMatch")?));
+        }
+
         Ok(match error!("LetMatch", value.pop_front(), [Token::Identifier(_), Token::AngleBracketOpen, Token::BracketOpen, Token::DoubleDot, Token::ParenOpen])? {
             iden @ Token::Identifier(_)
                 if matches!(
                     value.first(),
-                    Some(&Token::CurlyOpen | &Token::Keyword(Keywords::LeftArrow))
+                    Some(&Token::CurlyOpen | &Token::Keyword(Keywords::LeftArrow | Keywords::TurboStart))
                 ) =>
             {
                 value.tokens.push_front(iden);
-                let namespace = error!(NamespacedType::try_from(&mut *value), "LetMatch")?;
+                let namespace = error!(Path::try_from(&mut *value), "LetMatch")?;
                 let mut fields = vec![];
                 value.pop_front();
 
@@ -399,6 +761,7 @@ impl ToString for Destructuring {
                 }[2..]
             ),
             Self::Variable(var) => var.to_string(),
+            Self::Binding(binding) => binding.to_string(),
             Self::Rest => format!(".."),
         }
     }
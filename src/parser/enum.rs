@@ -1,16 +1,27 @@
-use crate::tokenizer::{Keywords, Token};
+use std::collections::HashSet;
+
+use crate::tokenizer::{BuiltinTypes, Int, Keywords, Literals, Token};
 
 use super::{
     attribute::Attribute,
+    defun::Scope,
     error,
+    exp::Exp,
     r#struct::StructFields,
-    r#type::{Generic, Type},
-    Parser, ParserError, ParserErrorStack,
+    r#type::{GenericParams, Type, Where},
+    Error, Parser, ParserError, ParserErrorStack,
 };
+
+/// (enum Opcode->u8 (Nop = 0) Load (Store = 0x10))
+/// (enum Opcode->u8 as Nop Load) also converts between the enum and its repr
 #[derive(Debug)]
 pub struct Enum {
+    scope: Scope,
     name: String,
-    generics: Vec<Generic>,
+    repr: Option<Type>,
+    conversions: bool,
+    params: GenericParams,
+    r#where: Where,
     variants: Vec<Variant>,
 }
 
@@ -19,21 +30,37 @@ impl TryFrom<&mut Parser> for Enum {
 
     fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
         let _ = error!("Enum", value.pop_front(), [Token::ParenOpen])?;
+        let scope = error!(Scope::try_from(&mut *value), "Enum")?;
         let _ = error!("Enum", value.pop_front(), [Token::Keyword(Keywords::Enum)])?;
         let name = error!("Enum", value);
+        let repr = if value.first() == Some(&Token::Keyword(Keywords::LeftArrow)) {
+            value.pop_front();
+            let repr = error!(Type::try_from(&mut *value), "Enum")?;
 
-        let mut generics = vec![];
+            if !matches!(&repr, Type::Builtin(builtin) if int_range(builtin).is_some())
+                && !matches!(&repr, Type::Custom(name) if name == "usize" || name == "isize")
+            {
+                return Err(error!(
+                    "Enum",
+                    Error::Other(format!("Expected an integer repr, got {}", repr.to_string()))
+                ));
+            }
 
-        loop {
-            let peek = value.first_err("Enum")?;
+            Some(repr)
+        } else {
+            None
+        };
 
-            if let Token::Char(':') = peek {
-                generics.push(error!(Generic::try_from(&mut *value), "Enum")?)
-            } else {
-                break;
-            }
-        }
+        let conversions = if value.first() == Some(&Token::Keyword(Keywords::As)) {
+            value.pop_front();
+            true
+        } else {
+            false
+        };
+
+        let params = error!(GenericParams::try_from(&mut *value), "Enum")?;
 
+        let r#where = error!(Where::try_from(&mut *value), "Enum")?;
         let mut variants = vec![];
 
         loop {
@@ -47,9 +74,26 @@ impl TryFrom<&mut Parser> for Enum {
             variants.push(error!(Variant::try_from(&mut *value), "Enum")?);
         }
 
+        validate_discriminants(&repr, &variants)?;
+
+        if conversions
+            && (repr.is_none() || !params.is_empty() || !variants.iter().all(|variant| variant.is_unit()))
+        {
+            return Err(error!(
+                "Enum",
+                Error::Other(format!(
+                    "Only enums with a repr and unit variants can convert to their repr, {name} can't"
+                ))
+            ));
+        }
+
         Ok(Self {
+            scope,
             name,
-            generics,
+            repr,
+            conversions,
+            params,
+            r#where,
             variants,
         })
     }
@@ -58,28 +102,144 @@ impl TryFrom<&mut Parser> for Enum {
 impl ToString for Enum {
     fn to_string(&self) -> String {
         format!(
-            "enum {}{} {{{}}}",
-            self.name,
-            if !self.generics.is_empty() {
-                format!(
-                    "<{}>",
-                    &self.generics.iter().fold(String::new(), |str, generic| {
-                        format!("{str}, {}", generic.to_string())
-                    })[2..]
-                )
+            "{}{}enum {}{}{} {{{}}}{}",
+            if let Some(repr) = &self.repr {
+                format!("#[repr({})]\n", repr.to_string())
             } else {
-                format!("")
+                String::new()
             },
+            self.scope.to_string(),
+            self.name,
+            self.params.to_string(),
+            self.r#where.to_string(),
             &self.variants.iter().fold(String::new(), |str, variant| {
                 format!("{str}, {}", variant.to_string())
-            })[2..]
+            })[2..],
+            match &self.repr {
+                Some(repr) if self.conversions => self.conversions(&repr.to_string()),
+                _ => String::new(),
+            }
+        )
+    }
+}
+
+impl Enum {
+    /// From and TryFrom impls between a unit only enum and its repr
+    fn conversions(&self, repr: &str) -> String {
+        let name = &self.name;
+
+        format!(
+            "\nimpl From<{name}> for {repr} {{fn from(value: {name}) -> {repr} {{value as {repr}}}}}\n\
+            impl TryFrom<{repr}> for {name} {{type Error = {repr};\n\
+            fn try_from(value: {repr}) -> Result<Self, {repr}> {{match value {{{}\n_ => Err(value)}}}}}}",
+            self.variants.iter().fold(String::new(), |str, variant| {
+                format!(
+                    "{str}\nx if x == Self::{0} as {repr} => Ok(Self::{0}),",
+                    variant.name()
+                )
+            })
         )
     }
 }
 
+/// Checks that the discriminants are integers, fit in the repr and don't repeat,
+/// counting up from the previous one like rust does for variants without one
+fn validate_discriminants(repr: &Option<Type>, variants: &Vec<Variant>) -> Result<(), ParserError> {
+    if variants.iter().all(|variant| variant.discriminant().is_none()) {
+        return Ok(());
+    }
+
+    if repr.is_none() && !variants.iter().all(|variant| variant.is_unit()) {
+        return Err(error!(
+            "Enum",
+            Error::Other("Discriminants on an enum with fields need a repr".to_string())
+        ));
+    }
+
+    let range = match repr {
+        Some(Type::Builtin(builtin)) => int_range(builtin),
+        _ => None,
+    };
+    let mut seen = HashSet::new();
+    // Sign and magnitude, so both i128::MIN and u128::MAX fit
+    let mut next = Some((false, 0u128));
+
+    for variant in variants {
+        let current = match variant.discriminant() {
+            Some(Exp::Literal(Literals::Int(Int(neg, num)))) => Some((*neg && *num != 0, *num)),
+            Some(Exp::Literal(literal)) => {
+                return Err(error!(
+                    "Enum",
+                    Error::Other(format!(
+                        "Expected an integer discriminant for {}, got {}",
+                        variant.name(),
+                        literal.to_string()
+                    ))
+                ))
+            }
+            // Anything else is left for rustc to evaluate
+            Some(_) => None,
+            None => next,
+        };
+
+        if let Some((neg, num)) = current {
+            if let Some((below, above)) = range {
+                if num > if neg { below } else { above } {
+                    return Err(error!(
+                        "Enum",
+                        Error::Other(format!(
+                            "Discriminant {} of {} doesn't fit in {}",
+                            Int(neg, num).to_string(),
+                            variant.name(),
+                            repr.as_ref().unwrap().to_string()
+                        ))
+                    ));
+                }
+            }
+
+            if !seen.insert((neg, num)) {
+                return Err(error!(
+                    "Enum",
+                    Error::Other(format!(
+                        "Duplicate discriminant {} for {}",
+                        Int(neg, num).to_string(),
+                        variant.name()
+                    ))
+                ));
+            }
+        }
+
+        next = current.and_then(|(neg, num)| match neg {
+            true => Some((num > 1, num - 1)),
+            false => num.checked_add(1).map(|num| (false, num)),
+        });
+    }
+
+    Ok(())
+}
+
+/// Largest negative and positive magnitude a repr can hold
+fn int_range(builtin: &BuiltinTypes) -> Option<(u128, u128)> {
+    match builtin {
+        BuiltinTypes::U8 => Some((0, u8::MAX as u128)),
+        BuiltinTypes::U16 => Some((0, u16::MAX as u128)),
+        BuiltinTypes::U32 => Some((0, u32::MAX as u128)),
+        BuiltinTypes::U64 => Some((0, u64::MAX as u128)),
+        BuiltinTypes::U128 => Some((0, u128::MAX)),
+        BuiltinTypes::I8 => Some((i8::MIN.unsigned_abs() as u128, i8::MAX as u128)),
+        BuiltinTypes::I16 => Some((i16::MIN.unsigned_abs() as u128, i16::MAX as u128)),
+        BuiltinTypes::I32 => Some((i32::MIN.unsigned_abs() as u128, i32::MAX as u128)),
+        BuiltinTypes::I64 => Some((i64::MIN.unsigned_abs() as u128, i64::MAX as u128)),
+        BuiltinTypes::I128 => Some((i128::MIN.unsigned_abs(), i128::MAX as u128)),
+        _ => None,
+    }
+}
+
 #[derive(Debug)]
 enum Variant {
     Simple(String),
+    /// (Load = 0x10)
+    Discriminant(String, Exp),
     WithType(String, Vec<Type>),
     Struct(String, StructFields),
     Attr(Attribute, Box<Variant>),
@@ -106,6 +266,13 @@ impl TryFrom<&mut Parser> for Variant {
                         value.pop_front();
                         return Ok(Variant::Simple(name));
                     }
+                    &Token::Char('=') => {
+                        value.pop_front();
+                        let discriminant = error!(Exp::try_from(&mut *value), "Variant")?;
+                        let _ = error!("Variant", value.pop_front(), [Token::ParenClose])?;
+
+                        Ok(Variant::Discriminant(name, discriminant))
+                    }
                     &Token::CurlyOpen => {
                         let fields = error!(StructFields::try_from(&mut *value), "Variant")?;
 
@@ -141,6 +308,9 @@ impl ToString for Variant {
     fn to_string(&self) -> String {
         match self {
             Self::Simple(name) => format!("{name}"),
+            Self::Discriminant(name, discriminant) => {
+                format!("{name} = {}", discriminant.to_string())
+            }
             Self::WithType(name, types) => format!(
                 "{name}({})",
                 &types.iter().fold(String::new(), |str, r#type| {
@@ -152,3 +322,31 @@ impl ToString for Variant {
         }
     }
 }
+
+impl Variant {
+    fn name(&self) -> &String {
+        match self {
+            Self::Simple(name)
+            | Self::Discriminant(name, _)
+            | Self::WithType(name, _)
+            | Self::Struct(name, _) => name,
+            Self::Attr(_, variant) => variant.name(),
+        }
+    }
+
+    fn is_unit(&self) -> bool {
+        match self {
+            Self::Simple(_) | Self::Discriminant(..) => true,
+            Self::WithType(..) | Self::Struct(..) => false,
+            Self::Attr(_, variant) => variant.is_unit(),
+        }
+    }
+
+    fn discriminant(&self) -> Option<&Exp> {
+        match self {
+            Self::Discriminant(_, discriminant) => Some(discriminant),
+            Self::Attr(_, variant) => variant.discriminant(),
+            _ => None,
+        }
+    }
+}
@@ -7,10 +7,11 @@ use super::{
     r#do::Do,
     r#if::If,
     r#let::Let,
+    r#loop::Loop,
     r#match::Match,
-    r#type::NamespacedType,
+    path::Path,
     range::Range,
-    turbofish::{TurboFish, TurboIden},
+    turbofish::TurboIden,
     Error, Parser, ParserError, ParserErrorStack,
 };
 
@@ -20,6 +21,14 @@ pub enum Exp {
     If(Box<If>),
     Match(Box<Match>),
     Let(Box<Let>),
+    Loop(Box<Loop>),
+    /// (async move
+    ///     let body <- ((client.get url).await.text)
+    ///     (body.len))
+    Async(bool, Box<Do>),
+    Await(Box<Exp>),
+    /// (unsafe (libc->strlen ptr))
+    Unsafe(Box<Do>),
     As(Box<As>),
     FuncCall(Box<Exp>, Vec<Exp>),
     MethodCall(Box<Exp>, TurboIden, Vec<Exp>),
@@ -34,11 +43,13 @@ pub enum Exp {
     Do(Box<Do>),
     Variable(String),
     Field(Box<Exp>, String),
+    Index(Box<Exp>, Box<Exp>),
     Literal(Literals),
     TypeCreation(TypeCreation),
-    Return(Box<Exp>),
+    Return(Option<Box<Exp>>),
+    Break(Option<String>, Option<Box<Exp>>),
+    Continue(Option<String>),
     ErrorOut(Box<Exp>),
-    TurboFish(TurboFish),
 }
 
 impl TryFrom<&mut Parser> for Exp {
@@ -55,18 +66,16 @@ impl TryFrom<&mut Parser> for Exp {
                 Token::Char('-'),
                 Token::Char('+'),
                 Token::Keyword(Keywords::Deref),
+                Token::DoubleDot,
                 Token::AngleBracketOpen,
                 Token::BracketOpen,
                 Token::ParenOpen
             ]
         )? {
             Token::Identifier(_) => match value.nth(1) {
-                Some(&Token::Keyword(Keywords::LeftArrow) | &Token::CurlyOpen) => {
-                    Self::TypeCreation(error!(TypeCreation::try_from(&mut *value), "Exp")?)
-                }
-                Some(&Token::Keyword(Keywords::TurboStart)) => {
-                    Self::TurboFish(error!(TurboFish::try_from(&mut *value), "Exp")?)
-                }
+                Some(
+                    &Token::Keyword(Keywords::LeftArrow | Keywords::TurboStart) | &Token::CurlyOpen,
+                ) => Self::TypeCreation(error!(TypeCreation::try_from(&mut *value), "Exp")?),
                 _ => {
                     Self::Variable(error!("Exp", value))
                 }
@@ -103,13 +112,46 @@ impl TryFrom<&mut Parser> for Exp {
 
                 Self::Deref(Box::new(error!(Self::try_from(&mut *value), "Exp")?))
             }
+            Token::DoubleDot => {
+                value.pop_front();
+                let inclusive = if value.first() == Some(&Token::Char('=')) {
+                    value.pop_front();
+                    true
+                } else {
+                    false
+                };
+
+                if matches!(value.first(), Some(&Token::ParenClose) | None) && !inclusive {
+                    Self::Range(Box::new(Range::Full))
+                } else {
+                    let end = error!(Exp::try_from(&mut *value), "Exp")?;
+
+                    Self::Range(Box::new(if inclusive {
+                        Range::ToInclusive(end)
+                    } else {
+                        Range::To(end)
+                    }))
+                }
+            }
             Token::AngleBracketOpen => {
                 Self::TypeCreation(error!(TypeCreation::try_from(&mut *value), "Exp")?)
             }
             Token::BracketOpen => {
                 Self::TypeCreation(error!(TypeCreation::try_from(&mut *value), "Exp")?)
             }
+            Token::ParenOpen if Path::is_qualified(value, 0) || Path::is_qualified(value, 1) => {
+                Self::TypeCreation(error!(TypeCreation::try_from(&mut *value), "Exp")?)
+            }
             Token::ParenOpen => match value.nth_err(1, "Exp")? {
+                Token::Char('|') if value.nth(2) == Some(&Token::AngleBracketClose) => {
+                    error!(Self::thread(&mut *value), "Exp")?
+                }
+                Token::Identifier(iden) if iden == "cond" => {
+                    Self::If(Box::new(error!(If::cond(&mut *value), "Exp")?))
+                }
+                Token::Identifier(iden) if iden == "when" || iden == "unless" => {
+                    Self::Do(Box::new(error!(Do::when(&mut *value), "Exp")?))
+                }
                 Token::Keyword(Keywords::Not) => {
                     value.pop_front();
                     value.pop_front();
@@ -137,11 +179,107 @@ impl TryFrom<&mut Parser> for Exp {
                 Token::Keyword(Keywords::Do) => {
                     Self::Do(Box::new(error!(Do::try_from(&mut *value), "Exp")?))
                 }
+                Token::Identifier(iden) if iden == "async" => {
+                    value.pop_front();
+                    value.pop_front();
+                    let r#move = if matches!(value.first(), Some(Token::Identifier(iden)) if iden == "move") {
+                        value.pop_front();
+                        true
+                    } else {
+                        false
+                    };
+
+                    Self::Async(r#move, Box::new(error!(Do::body(&mut *value), "Exp")?))
+                }
+                Token::Identifier(iden) if iden == "unsafe" => {
+                    value.pop_front();
+                    value.pop_front();
+
+                    Self::Unsafe(Box::new(error!(Do::body(&mut *value), "Exp")?))
+                }
+                Token::Identifier(iden) if iden == "idx" => {
+                    value.pop_front();
+                    value.pop_front();
+                    let mut exp = error!(Exp::try_from(&mut *value), "Exp")?;
+
+                    loop {
+                        let peek = value.first_err("Exp")?;
+
+                        if peek == &Token::ParenClose {
+                            value.pop_front();
+                            break;
+                        }
+
+                        exp = Self::Index(
+                            Box::new(exp),
+                            Box::new(error!(Exp::try_from(&mut *value), "Exp")?),
+                        );
+                    }
+
+                    if !matches!(exp, Self::Index(..)) {
+                        return Err(error!(
+                            "Exp",
+                            Error::Other("Expected at least one index in idx".to_string())
+                        ));
+                    }
+
+                    exp
+                }
+                Token::Keyword(Keywords::Loop) => {
+                    Self::Loop(Box::new(error!(Loop::try_from(&mut *value), "Exp")?))
+                }
                 Token::Keyword(Keywords::Return) => {
                     value.pop_front();
                     value.pop_front();
 
-                    Self::Return(Box::new(error!(Exp::try_from(&mut *value), "Exp")?))
+                    if value.first() == Some(&Token::ParenClose) {
+                        value.pop_front();
+                        Self::Return(None)
+                    } else {
+                        let ret = Self::Return(Some(Box::new(error!(
+                            Exp::try_from(&mut *value),
+                            "Exp"
+                        )?)));
+                        let _ = error!("Exp", value.pop_front(), [Token::ParenClose])?;
+
+                        ret
+                    }
+                }
+                Token::Keyword(Keywords::Break | Keywords::Continue) => {
+                    value.pop_front();
+                    let r#break = value.pop_front() == Some(Token::Keyword(Keywords::Break));
+                    let label = if let Some(&Token::Lifetime(_)) = value.first() {
+                        let Some(Token::Lifetime(label)) = value.pop_front() else {
+                            unreachable!()
+                        };
+
+                        Some(label)
+                    } else {
+                        None
+                    };
+
+                    if value.first() == Some(&Token::ParenClose) {
+                        value.pop_front();
+
+                        if r#break {
+                            Self::Break(label, None)
+                        } else {
+                            Self::Continue(label)
+                        }
+                    } else if r#break {
+                        let ret = Self::Break(
+                            label,
+                            Some(Box::new(error!(Exp::try_from(&mut *value), "Exp")?)),
+                        );
+                        let _ = error!("Exp", value.pop_front(), [Token::ParenClose])?;
+
+                        ret
+                    } else {
+                        return Err(error!(
+                            "Exp",
+                            Error::Other("continue doesn't take a value".to_string())
+                        ));
+                    }
                 }
                 Token::Identifier(_) => match value.nth(2) {
                     Some(&Token::Slash | &Token::Char('.')) => {
@@ -158,14 +296,16 @@ impl TryFrom<&mut Parser> for Exp {
                                     value.pop_front();
                                     Self::Field(
                                         Box::new(exp),
-                                        error!("Exp", value),
+                                        error!(Self::field(&mut *value), "Exp")?,
                                     )
                                 }
                                 Token::Char('.') => {
                                     value.pop_front();
                                     let method = error!(TurboIden::try_from(&mut *value), "Exp")?;
 
-                                    if matches!(
+                                    if matches!(&method, TurboIden::Identifier(iden) if iden == "await") {
+                                        Self::Await(Box::new(exp))
+                                    } else if matches!(
                                         value.first(),
                                         Some(&Token::Slash | &Token::Char('.'))
                                     ) {
@@ -197,9 +337,10 @@ impl TryFrom<&mut Parser> for Exp {
                             }
                         }
                     }
-                    Some(&Token::Keyword(Keywords::LeftArrow) | &Token::CurlyOpen) => {
-                        Self::TypeCreation(error!(TypeCreation::try_from(&mut *value), "Exp")?)
-                    }
+                    Some(
+                        &Token::Keyword(Keywords::LeftArrow | Keywords::TurboStart)
+                        | &Token::CurlyOpen,
+                    ) => Self::TypeCreation(error!(TypeCreation::try_from(&mut *value), "Exp")?),
                     _ => {
                         value.pop_front();
                         let func = error!("Exp", value);
@@ -246,14 +387,16 @@ impl TryFrom<&mut Parser> for Exp {
                                     value.pop_front();
                                     Self::Field(
                                         Box::new(exp),
-                                        error!("Exp", value),
+                                        error!(Self::field(&mut *value), "Exp")?,
                                     )
                                 }
                                 Token::Char('.') => {
                                     value.pop_front();
                                     let method = error!(TurboIden::try_from(&mut *value), "Exp")?;
 
-                                    if matches!(
+                                    if matches!(&method, TurboIden::Identifier(iden) if iden == "await") {
+                                        Self::Await(Box::new(exp))
+                                    } else if matches!(
                                         value.first(),
                                         Some(&Token::Slash | &Token::Char('.'))
                                     ) {
@@ -335,9 +478,14 @@ impl TryFrom<&mut Parser> for Exp {
                         }))
                     }
                 }
-                Some(&Token::Slash) => {
+                Some(&Token::Slash)
+                    if matches!(
+                        value.nth(1),
+                        Some(&Token::Identifier(_) | &Token::Literal(Literals::Int(Int(false, _))))
+                    ) =>
+                {
                     value.pop_front();
-                    Self::Field(Box::new(ret), error!("Exp", value))
+                    Self::Field(Box::new(ret), error!(Self::field(&mut *value), "Exp")?)
                 }
                 _ => return Ok(ret),
             };
@@ -345,6 +493,99 @@ impl TryFrom<&mut Parser> for Exp {
     }
 }
 
+impl Exp {
+    /// (|> x (f a) (.map g) .collect) threads x through as the first arg, |>> as the last one.
+    /// Methods always get it as the receiver and ? can follow any step
+    fn thread(value: &mut Parser) -> Result<Self, ParserError> {
+        let _ = error!("Thread", value.pop_front(), [Token::ParenOpen])?;
+        let _ = error!("Thread", value.pop_front(), [Token::Char('|')])?;
+        let _ = error!("Thread", value.pop_front(), [Token::AngleBracketClose])?;
+        let last = if value.first() == Some(&Token::AngleBracketClose) {
+            value.pop_front();
+            true
+        } else {
+            false
+        };
+        let mut exp = error!(Self::try_from(&mut *value), "Thread")?;
+
+        loop {
+            exp = match error!("Thread", value.first(), [Token::Char('.' | '?'), Token::ParenOpen, Token::ParenClose, Token::Identifier(_)])? {
+                Token::ParenClose => {
+                    value.pop_front();
+                    break Ok(exp);
+                }
+                Token::Char('?') => {
+                    value.pop_front();
+                    Self::ErrorOut(Box::new(exp))
+                }
+                Token::Char('.') => {
+                    value.pop_front();
+
+                    match error!(TurboIden::try_from(&mut *value), "Thread")? {
+                        TurboIden::Identifier(iden) if iden == "await" => Self::Await(Box::new(exp)),
+                        method => Self::MethodCall(Box::new(exp), method, vec![]),
+                    }
+                }
+                Token::ParenOpen if value.nth(1) == Some(&Token::Char('.')) => {
+                    value.pop_front();
+                    value.pop_front();
+                    let method = error!(TurboIden::try_from(&mut *value), "Thread")?;
+                    let mut args = vec![];
+
+                    while value.first_err("Thread")? != &Token::ParenClose {
+                        args.push(error!(Self::try_from(&mut *value), "Thread")?);
+                    }
+                    value.pop_front();
+
+                    Self::MethodCall(Box::new(exp), method, args)
+                }
+                Token::ParenOpen => {
+                    value.pop_front();
+                    let func = error!(Self::try_from(&mut *value), "Thread")?;
+                    let mut args = vec![];
+
+                    while value.first_err("Thread")? != &Token::ParenClose {
+                        args.push(error!(Self::try_from(&mut *value), "Thread")?);
+                    }
+                    value.pop_front();
+
+                    if last {
+                        args.push(exp);
+                    } else {
+                        args.insert(0, exp);
+                    }
+
+                    Self::FuncCall(Box::new(func), args)
+                }
+                _ => Self::FuncCall(
+                    Box::new(error!(Self::try_from(&mut *value), "Thread")?),
+                    vec![exp],
+                ),
+            }
+        }
+    }
+
+    /// A named field or a tuple index: point/x, pair/0
+    fn field(value: &mut Parser) -> Result<String, ParserError> {
+        match error!(
+            "Exp",
+            value.pop_front(),
+            [Token::Identifier(_), Token::Literal(Literals::Int(Int(false, _)))]
+        )? {
+            Token::Identifier(iden) => Ok(iden),
+            Token::Literal(Literals::Int(Int(_, index))) => Ok(index.to_string()),
+            _ => unreachable!(),
+        }
+    }
+
+    pub(crate) fn is_place(&self) -> bool {
+        matches!(
+            self,
+            Self::Variable(_) | Self::Field(..) | Self::Deref(_) | Self::Index(..)
+        )
+    }
+}
+
 impl ToString for Exp {
     fn to_string(&self) -> String {
         match self {
@@ -352,6 +593,11 @@ impl ToString for Exp {
             Self::If(r#if) => format!("{}", r#if.to_string()),
             Self::Match(r#match) => format!("{}", r#match.to_string()),
             Self::Let(r#let) => format!("{{{}}}", r#let.to_string()),
+            Self::Loop(r#loop) => r#loop.to_string(),
+            Self::Async(true, body) => format!("async move {}", body.to_string()),
+            Self::Async(false, body) => format!("async {}", body.to_string()),
+            Self::Await(exp) => format!("{}.await", exp.to_string()),
+            Self::Unsafe(body) => format!("unsafe {}", body.to_string()),
             Self::As(r#as) => format!("({})", r#as.to_string()),
             Self::FuncCall(func, args) => format!(
                 "{}({})",
@@ -379,6 +625,8 @@ impl ToString for Exp {
             Self::Ref(exp) => format!("&{}", exp.to_string()),
             Self::MutRef(exp) => format!("&mut {}", exp.to_string()),
             Self::Deref(exp) => format!("*{}", exp.to_string()),
+            // ! binds tighter than the operators
+            Self::Not(exp) if matches!(**exp, Self::Infix(_)) => format!("!({})", exp.to_string()),
             Self::Not(exp) => format!("!{}", exp.to_string()),
             Self::Positive(exp) => format!("+{}", exp.to_string()),
             Self::Negation(exp) => format!("-{}", exp.to_string()),
@@ -387,23 +635,46 @@ impl ToString for Exp {
             Self::Do(r#do) => format!("{}", r#do.to_string()),
             Self::Variable(var) => var.to_string(),
             Self::Field(exp, field) => format!("{}.{field}", exp.to_string()),
+            Self::Index(exp, index) => match **exp {
+                Self::Ref(_)
+                | Self::MutRef(_)
+                | Self::Deref(_)
+                | Self::Not(_)
+                | Self::Negation(_)
+                | Self::Positive(_) => format!("({})[{}]", exp.to_string(), index.to_string()),
+                _ => format!("{}[{}]", exp.to_string(), index.to_string()),
+            },
             Self::Literal(literal) => literal.to_string(),
             Self::TypeCreation(creation) => format!("{}", creation.to_string()),
-            Self::Return(exp) => format!("return {}", exp.to_string()),
+            Self::Return(Some(exp)) => format!("return {}", exp.to_string()),
+            Self::Return(None) => "return".to_string(),
+            Self::Break(label, exp) => format!(
+                "break{}{}",
+                match label {
+                    Some(label) => format!(" '{label}"),
+                    None => String::new(),
+                },
+                match exp {
+                    Some(exp) => format!(" {}", exp.to_string()),
+                    None => String::new(),
+                }
+            ),
+            Self::Continue(Some(label)) => format!("continue '{label}"),
+            Self::Continue(None) => "continue".to_string(),
             Self::ErrorOut(exp) => format!("{}?", exp.to_string()),
-            Self::TurboFish(turbofish) => turbofish.to_string(),
         }
     }
 }
 
 #[derive(Debug)]
 pub enum TypeCreation {
-    Simple(NamespacedType),
-    Vars(NamespacedType, Vec<Exp>),
-    Struct(NamespacedType, Vec<(String, Exp)>),
+    Simple(Path),
+    Vars(Path, Vec<Exp>),
+    Struct(Path, Vec<(String, Exp)>),
     Touple(Vec<Exp>),
     Array(Vec<Exp>),
-    ArrayLen(Box<Exp>, usize),
+    /// [0; (* 4 SIZE)]
+    ArrayLen(Box<Exp>, Box<Exp>),
 }
 
 impl TryFrom<&mut Parser> for TypeCreation {
@@ -412,7 +683,7 @@ impl TryFrom<&mut Parser> for TypeCreation {
     fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
         let mut paren = false;
         let peek = value.first_err("TypeCreation")?;
-        if peek == &Token::ParenOpen {
+        if peek == &Token::ParenOpen && !Path::is_qualified(value, 0) {
             value.pop_front();
             paren = true;
         } else if peek == &Token::AngleBracketOpen {
@@ -446,20 +717,14 @@ impl TryFrom<&mut Parser> for TypeCreation {
                     Token::Char(';') => {
                         value.pop_front();
 
-                        let ret = match error!(
-                            "TypeCreation",
-                            value.pop_front(),
-                            [Token::Literal(Literals::Int(Int(false, _)))]
-                        )? {
-                            Token::Literal(Literals::Int(Int(false, len))) => Ok(Self::ArrayLen(
-                                Box::new(exps.into_iter().next().ok_or(error!(
-                                    "TypeCreation",
-                                    Error::Other(format!("Expected an expression"))
-                                ))?),
-                                len as usize,
-                            )),
-                            _ => unreachable!(),
-                        };
+                        let len = error!(Exp::try_from(&mut *value), "TypeCreation")?;
+                        let ret = Ok(Self::ArrayLen(
+                            Box::new(exps.into_iter().next().ok_or(error!(
+                                "TypeCreation",
+                                Error::Other("Expected an expression".to_string())
+                            ))?),
+                            Box::new(len),
+                        ));
                         let _ = error!("TypeCreation", value.pop_front(), [Token::BracketClose])?;
 
                         return ret;
@@ -469,7 +734,7 @@ impl TryFrom<&mut Parser> for TypeCreation {
             }
         }
 
-        let name = error!(NamespacedType::try_from(&mut *value), "TypeCreation")?;
+        let name = error!(Path::try_from(&mut *value), "TypeCreation")?;
 
         let ret = match value.first() {
             Some(Token::CurlyOpen) => {
@@ -591,6 +856,47 @@ pub enum Infix {
     BitwiseOr(Exp, Exp),
     LeftShift(Exp, Exp),
     RightShift(Exp, Exp),
+    /// (< 0 i len), every operand is evaluated once before comparing
+    Chain(&'static str, Vec<Exp>),
+}
+
+impl Infix {
+    /// (+ a b c) is ((a + b) + c)
+    fn left(operands: Vec<Exp>, infix: fn(Exp, Exp) -> Self) -> Self {
+        let mut operands = operands.into_iter();
+        let first = operands.next().unwrap();
+        let second = operands.next().unwrap();
+
+        operands.fold(infix(first, second), |left, right| {
+            infix(Exp::Infix(Box::new(left)), right)
+        })
+    }
+
+    /// (< a b c) is a < b && b < c
+    fn chain(mut operands: Vec<Exp>, infix: fn(Exp, Exp) -> Self, op: &'static str) -> Self {
+        if operands.len() == 2 {
+            let right = operands.pop().unwrap();
+            let left = operands.pop().unwrap();
+
+            infix(left, right)
+        } else {
+            Self::Chain(op, operands)
+        }
+    }
+}
+
+/// At least two operands, up to the closing paren
+fn operands(value: &mut Parser) -> Result<Vec<Exp>, ParserError> {
+    let mut operands = vec![
+        error!(Exp::try_from(&mut *value), "Operands")?,
+        error!(Exp::try_from(&mut *value), "Operands")?,
+    ];
+
+    while value.first_err("Operands")? != &Token::ParenClose {
+        operands.push(error!(Exp::try_from(&mut *value), "Operands")?);
+    }
+
+    Ok(operands)
 }
 
 impl TryFrom<&mut Parser> for Infix {
@@ -617,110 +923,65 @@ impl TryFrom<&mut Parser> for Infix {
             )? {
                 Token::Char('+') => {
                     value.pop_front();
-                    Self::Add(
-                        error!(Exp::try_from(&mut *value), "Infix")?,
-                        error!(Exp::try_from(&mut *value), "Infix")?,
-                    )
+                    Self::left(error!(operands(&mut *value), "Infix")?, Self::Add)
                 }
                 Token::Char('-') => {
                     value.pop_front();
-                    Self::Subtract(
-                        error!(Exp::try_from(&mut *value), "Infix")?,
-                        error!(Exp::try_from(&mut *value), "Infix")?,
-                    )
+                    Self::left(error!(operands(&mut *value), "Infix")?, Self::Subtract)
                 }
                 Token::Char('*') => {
                     value.pop_front();
-                    Self::Multiply(
-                        error!(Exp::try_from(&mut *value), "Infix")?,
-                        error!(Exp::try_from(&mut *value), "Infix")?,
-                    )
+                    Self::left(error!(operands(&mut *value), "Infix")?, Self::Multiply)
                 }
                 Token::Slash => {
                     value.pop_front();
                     if value.first() == Some(&Token::Char('=')) {
                         value.pop_front();
-                        Self::NotEquality(
-                            error!(Exp::try_from(&mut *value), "Infix")?,
-                            error!(Exp::try_from(&mut *value), "Infix")?,
-                        )
+                        Self::chain(error!(operands(&mut *value), "Infix")?, Self::NotEquality, "!=")
                     } else {
-                        Self::Divide(
-                            error!(Exp::try_from(&mut *value), "Infix")?,
-                            error!(Exp::try_from(&mut *value), "Infix")?,
-                        )
+                        Self::left(error!(operands(&mut *value), "Infix")?, Self::Divide)
                     }
                 }
                 Token::Char('%') => {
                     value.pop_front();
-                    Self::Modulo(
-                        error!(Exp::try_from(&mut *value), "Infix")?,
-                        error!(Exp::try_from(&mut *value), "Infix")?,
-                    )
+                    Self::left(error!(operands(&mut *value), "Infix")?, Self::Modulo)
                 }
                 Token::Char('=') => {
                     value.pop_front();
-                    Self::Equality(
-                        error!(Exp::try_from(&mut *value), "Infix")?,
-                        error!(Exp::try_from(&mut *value), "Infix")?,
-                    )
+                    Self::chain(error!(operands(&mut *value), "Infix")?, Self::Equality, "==")
                 }
                 Token::Keyword(Keywords::And) => {
                     value.pop_front();
-                    Self::And(
-                        error!(Exp::try_from(&mut *value), "Infix")?,
-                        error!(Exp::try_from(&mut *value), "Infix")?,
-                    )
+                    Self::left(error!(operands(&mut *value), "Infix")?, Self::And)
                 }
                 Token::Keyword(Keywords::Or) => {
                     value.pop_front();
-                    Self::Or(
-                        error!(Exp::try_from(&mut *value), "Infix")?,
-                        error!(Exp::try_from(&mut *value), "Infix")?,
-                    )
+                    Self::left(error!(operands(&mut *value), "Infix")?, Self::Or)
                 }
                 Token::Keyword(Keywords::Xor) => {
                     value.pop_front();
-                    Self::Xor(
-                        error!(Exp::try_from(&mut *value), "Infix")?,
-                        error!(Exp::try_from(&mut *value), "Infix")?,
-                    )
+                    Self::left(error!(operands(&mut *value), "Infix")?, Self::Xor)
                 }
                 Token::Keyword(Keywords::BitwiseAnd) => {
                     value.pop_front();
-                    Self::BitwiseAnd(
-                        error!(Exp::try_from(&mut *value), "Infix")?,
-                        error!(Exp::try_from(&mut *value), "Infix")?,
-                    )
+                    Self::left(error!(operands(&mut *value), "Infix")?, Self::BitwiseAnd)
                 }
                 Token::Keyword(Keywords::BitwiseOr) => {
                     value.pop_front();
-                    Self::BitwiseOr(
-                        error!(Exp::try_from(&mut *value), "Infix")?,
-                        error!(Exp::try_from(&mut *value), "Infix")?,
-                    )
+                    Self::left(error!(operands(&mut *value), "Infix")?, Self::BitwiseOr)
                 }
                 Token::AngleBracketOpen => {
                     value.pop_front();
                     match value.first() {
                         Some(&Token::Char('=')) => {
                             value.pop_front();
-                            Self::LessEq(
-                                error!(Exp::try_from(&mut *value), "Infix")?,
-                                error!(Exp::try_from(&mut *value), "Infix")?,
-                            )
+                            Self::chain(error!(operands(&mut *value), "Infix")?, Self::LessEq, "<=")
                         }
                         Some(&Token::AngleBracketOpen) => {
                             value.pop_front();
-                            Self::LeftShift(
-                                error!(Exp::try_from(&mut *value), "Infix")?,
-                                error!(Exp::try_from(&mut *value), "Infix")?,
-                            )
+                            Self::left(error!(operands(&mut *value), "Infix")?, Self::LeftShift)
                         }
-                        _ => Self::Less(
-                            error!(Exp::try_from(&mut *value), "Infix")?,
-                            error!(Exp::try_from(&mut *value), "Infix")?,
-                        ),
+                        _ => Self::chain(error!(operands(&mut *value), "Infix")?, Self::Less, "<"),
                     }
                 }
                 Token::AngleBracketClose => {
@@ -728,22 +989,13 @@ impl TryFrom<&mut Parser> for Infix {
                     match value.first() {
                         Some(&Token::Char('=')) => {
                             value.pop_front();
-                            Self::GreaterEq(
-                                error!(Exp::try_from(&mut *value), "Infix")?,
-                                error!(Exp::try_from(&mut *value), "Infix")?,
-                            )
+                            Self::chain(error!(operands(&mut *value), "Infix")?, Self::GreaterEq, ">=")
                         }
                         Some(&Token::AngleBracketClose) => {
                             value.pop_front();
-                            Self::RightShift(
-                                error!(Exp::try_from(&mut *value), "Infix")?,
-                                error!(Exp::try_from(&mut *value), "Infix")?,
-                            )
+                            Self::left(error!(operands(&mut *value), "Infix")?, Self::RightShift)
                         }
-                        _ => Self::Greater(
-                            error!(Exp::try_from(&mut *value), "Infix")?,
-                            error!(Exp::try_from(&mut *value), "Infix")?,
-                        ),
+                        _ => Self::chain(error!(operands(&mut *value), "Infix")?, Self::Greater, ">"),
                     }
                 }
                 _ => unreachable!(),
@@ -799,6 +1051,16 @@ impl ToString for Infix {
             Infix::RightShift(left, right) => {
                 format!("({}) >> ({})", left.to_string(), right.to_string())
             }
+            Infix::Chain(op, operands) => format!(
+                "{{{}{}}}",
+                operands.iter().enumerate().fold(String::new(), |str, (nth, exp)| {
+                    format!("{str}let __cmp{nth} = &({});\n", exp.to_string())
+                }),
+                (1..operands.len())
+                    .map(|nth| format!("(*__cmp{}) {op} (*__cmp{nth})", nth - 1))
+                    .collect::<Vec<String>>()
+                    .join(" && ")
+            ),
         }
     }
 }
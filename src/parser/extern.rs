@@ -0,0 +1,119 @@
+use crate::tokenizer::{Keywords, Literals, Token};
+
+use super::{
+    attribute::Attribute,
+    defun::{Defun, Qualifiers, Scope},
+    error,
+    r#const::Const,
+    Error, Parser, ParserError, ParserErrorStack,
+};
+
+/// #[(link (= name "m"))]
+/// (extern "C"
+///     (defun cos (x->f64)->f64)
+///     (defun printf (fmt->*const c_char ..)->i32)
+///     (static mut errno->i32))
+#[derive(Debug)]
+pub struct Extern {
+    abi: String,
+    items: Vec<ExternItem>,
+}
+
+#[derive(Debug)]
+pub enum ExternItem {
+    Function(Defun),
+    Static(Const),
+    Attribute(Attribute),
+}
+
+impl TryFrom<&mut Parser> for Extern {
+    type Error = ParserError;
+
+    fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
+        let _ = error!("Extern", value.pop_front(), [Token::ParenOpen])?;
+
+        if !matches!(error!(Scope::try_from(&mut *value), "Extern")?, Scope::File) {
+            return Err(error!(
+                "Extern",
+                Error::Other("Extern blocks can't have a scope, put it on the items inside".to_string())
+            ));
+        }
+
+        let r#extern = error!("Extern", value);
+
+        if &r#extern != "extern" {
+            return Err(error!(
+                "Extern",
+                Error::Other(format!("Expected extern, got {extern:?}"))
+            ));
+        }
+
+        let abi = match error!("Extern", value.pop_front(), [Token::Literal(Literals::String(_))])? {
+            Token::Literal(Literals::String(abi)) => abi,
+            _ => unreachable!(),
+        };
+        let mut items = vec![];
+
+        loop {
+            if value.first_err("Extern")? == &Token::ParenClose {
+                value.pop_front();
+                break;
+            }
+
+            items.push(error!(ExternItem::try_from(&mut *value), "Extern")?);
+        }
+
+        Ok(Self { abi, items })
+    }
+}
+
+impl ToString for Extern {
+    fn to_string(&self) -> String {
+        format!(
+            "extern \"{}\" {{{}}}",
+            self.abi,
+            self.items
+                .iter()
+                .map(|item| item.to_string())
+                .collect::<Vec<String>>()
+                .join("\n")
+        )
+    }
+}
+
+impl TryFrom<&mut Parser> for ExternItem {
+    type Error = ParserError;
+
+    fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
+        if value.first() == Some(&Token::Char('#')) {
+            return Ok(Self::Attribute(error!(Attribute::try_from(&mut *value), "ExternItem")?));
+        }
+
+        let nth = Qualifiers::skip(value, Scope::skip(value, 1));
+
+        match value.nth(nth) {
+            Some(Token::Keyword(Keywords::Defun)) => {
+                Ok(Self::Function(error!(Defun::foreign(&mut *value), "ExternItem")?))
+            }
+            Some(Token::Identifier(iden)) if iden == "static" || iden == "const" => {
+                Ok(Self::Static(error!(Const::foreign(&mut *value), "ExternItem")?))
+            }
+            token => Err(error!(
+                "ExternItem",
+                Error::Other(format!(
+                    "Extern blocks can only hold function headers and statics, got {token:#?}"
+                ))
+            )),
+        }
+    }
+}
+
+impl ToString for ExternItem {
+    fn to_string(&self) -> String {
+        match self {
+            Self::Function(function) => function.to_string(),
+            Self::Static(r#static) => r#static.to_string(),
+            Self::Attribute(attr) => attr.to_string(),
+        }
+    }
+}
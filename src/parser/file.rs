@@ -1,8 +1,8 @@
-use crate::tokenizer::{Keywords, Token};
+use crate::tokenizer::{Keywords, Literals, Token};
 
 use super::{
-    attribute::Attribute, defun::Defun, error, module::Mod, r#enum::Enum, r#impl::Impl,
-    r#struct::Struct, r#trait::Trait, r#type::TypeAlias, r#use::Use, Parser, ParserError,
+    attribute::Attribute, defun::{Defun, Qualifiers, Scope}, error, r#const::Const, r#macro, module::Mod, r#enum::Enum, r#extern::Extern, r#impl::Impl,
+    r#struct::Struct, r#trait::Trait, r#type::TypeAlias, r#use::Use, Error, Parser, ParserError,
     ParserErrorStack,
 };
 
@@ -13,6 +13,8 @@ impl TryFrom<&mut Parser> for File {
     type Error = ParserError;
 
     fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
+        error!(r#macro::expand(&mut *value), "File")?;
+
         let mut file_ops = vec![];
         while !value.tokens.is_empty() {
             file_ops.push(error!(FileOps::try_from(&mut *value), "File")?);
@@ -48,6 +50,8 @@ pub enum FileOps {
     Trait(Trait),
     TypeAlias(TypeAlias),
     Mod(Mod),
+    Const(Const),
+    Extern(Extern),
 }
 
 impl TryFrom<&mut Parser> for FileOps {
@@ -61,10 +65,28 @@ impl TryFrom<&mut Parser> for FileOps {
             )?));
         }
 
+        // Skip the scope and qualifiers to find out which item this is
+        let nth = Scope::skip(value, 1);
+
+        if matches!(value.nth(nth), Some(Token::Identifier(iden)) if iden == "extern")
+            && matches!(
+                value.nth(nth + 1),
+                Some(Token::Literal(Literals::String(_)))
+            )
+            && matches!(
+                value.nth(nth + 2),
+                Some(Token::ParenOpen | Token::ParenClose | Token::Char('#'))
+            )
+        {
+            return Ok(Self::Extern(error!(Extern::try_from(&mut *value), "FileOps")?));
+        }
+
+        let nth = Qualifiers::skip(value, nth);
+
         Ok(
             match error!(
                 "FileOps",
-                value.nth(1),
+                value.nth(nth),
                 [
                     Token::Keyword(
                         Keywords::Use
@@ -94,18 +116,6 @@ impl TryFrom<&mut Parser> for FileOps {
                 Token::Keyword(Keywords::Mod) => {
                     Self::Mod(error!(Mod::try_from(&mut *value), "FileOps")?)
                 }
-                Token::Identifier(iden) if &iden[..] == "pub" || &iden[..] == "crate" => {
-                    match error!("FileOps", value.nth(2), [Token::Keyword(Keywords::Defun | Keywords::Mod)])?
-                    {
-                        Token::Keyword(Keywords::Defun) => {
-                            Self::Function(error!(Defun::try_from(&mut *value), "FileOps")?)
-                        }
-                        Token::Keyword(Keywords::Mod) => {
-                            Self::Mod(error!(Mod::try_from(&mut *value), "FileOps")?)
-                        }
-                        _ => unreachable!(),
-                    }
-                }
                 Token::Keyword(Keywords::Impl) => {
                     Self::Impl(error!(Impl::try_from(&mut *value), "FileOps")?)
                 }
@@ -115,7 +125,18 @@ impl TryFrom<&mut Parser> for FileOps {
                 Token::Keyword(Keywords::Type) => {
                     Self::TypeAlias(error!(TypeAlias::try_from(&mut *value), "FileOps")?)
                 }
-                _ => unreachable!(),
+                Token::Identifier(iden) if iden == "const" || iden == "static" => {
+                    Self::Const(error!(Const::try_from(&mut *value), "FileOps")?)
+                }
+                Token::Identifier(iden) if iden == "union" => {
+                    Self::Struct(error!(Struct::try_from(&mut *value), "FileOps")?)
+                }
+                token => {
+                    return Err(error!(
+                        "FileOps",
+                        Error::Other(format!("Expected an item, got {token:#?}"))
+                    ))
+                }
             },
         )
     }
@@ -133,6 +154,8 @@ impl ToString for FileOps {
             Self::Trait(r#trait) => r#trait.to_string(),
             Self::TypeAlias(type_alias) => type_alias.to_string(),
             Self::Mod(module) => module.to_string(),
+            Self::Const(r#const) => r#const.to_string(),
+            Self::Extern(r#extern) => r#extern.to_string(),
         }
     }
 }
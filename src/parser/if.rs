@@ -1,12 +1,13 @@
 use crate::tokenizer::{Keywords, Token};
 
-use super::{error, exp::Exp, Parser, ParserError, ParserErrorStack};
+use super::{error, exp::Exp, r#match::Pattern, Error, Parser, ParserError, ParserErrorStack};
 #[derive(Debug)]
 pub struct If {
-    condition: Exp,
+    condition: Condition,
     true_branch: Exp,
-    elif_branch: Vec<(Exp, Exp)>,
-    false_branch: Exp,
+    elif_branch: Vec<(Condition, Exp)>,
+    /// Only left out by cond without an else clause
+    false_branch: Option<Exp>,
 }
 
 impl TryFrom<&mut Parser> for If {
@@ -15,29 +16,72 @@ impl TryFrom<&mut Parser> for If {
     fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
         let _ = error!("If", value.pop_front(), [Token::ParenOpen])?;
         let _ = error!("If", value.pop_front(), [Token::Keyword(Keywords::If)])?;
-        let condition = error!(Exp::try_from(&mut *value), "If")?;
+        let condition = error!(Condition::try_from(&mut *value), "If")?;
         let true_branch = error!(Exp::try_from(&mut *value), "If")?;
         let mut elif_branch = vec![];
 
+        while value.first() == Some(&Token::Keyword(Keywords::Elif)) {
+            value.pop_front();
+            elif_branch.push((error!(Condition::try_from(&mut *value), "If")?, error!(Exp::try_from(&mut *value), "If")?))
+        }
+
+        let _ = error!("If", value.pop_front(), [Token::Keyword(Keywords::Else)])?;
+        let false_branch = error!(Exp::try_from(&mut *value), "If")?;
+        let _ = error!("If", value.pop_front(), [Token::ParenClose])?;
+
+        Ok(Self {
+            condition,
+            true_branch,
+            elif_branch,
+            false_branch: Some(false_branch),
+        })
+    }
+}
+
+impl If {
+    /// (cond ((< n 0) -1) (let (Option->Some x) <- cached x) (else 0))
+    pub(crate) fn cond(value: &mut Parser) -> Result<Self, ParserError> {
+        let _ = error!("Cond", value.pop_front(), [Token::ParenOpen])?;
+        let _ = error!("Cond", value.pop_front(), [Token::Identifier(_)])?;
+        let mut clauses = vec![];
+        let mut false_branch = None;
+
         loop {
-            let peek = value.first();
+            if value.first_err("Cond")? == &Token::ParenClose {
+                value.pop_front();
+                break;
+            }
+
+            let _ = error!("Cond", value.pop_front(), [Token::ParenOpen])?;
 
-            if peek == Some(&Token::Keyword(Keywords::Elif)) {
+            if value.first() == Some(&Token::Keyword(Keywords::Else)) {
                 value.pop_front();
-                elif_branch.push((error!(Exp::try_from(&mut *value), "If")?, error!(Exp::try_from(&mut *value), "If")?))
+                false_branch = Some(error!(Exp::try_from(&mut *value), "Cond")?);
+                let _ = error!("Cond", value.pop_front(), [Token::ParenClose])?;
+                // else has to be the last clause
+                let _ = error!("Cond", value.pop_front(), [Token::ParenClose])?;
+                break;
             }
 
-            break
+            let condition = error!(Condition::try_from(&mut *value), "Cond")?;
+            let exp = error!(Exp::try_from(&mut *value), "Cond")?;
+            let _ = error!("Cond", value.pop_front(), [Token::ParenClose])?;
+
+            clauses.push((condition, exp));
         }
 
-        let _ = error!("If", value.pop_front(), [Token::Keyword(Keywords::Else)])?;
-        let false_branch = error!(Exp::try_from(&mut *value), "If")?;
-        let _ = error!("If", value.pop_front(), [Token::ParenClose])?;
+        let mut clauses = clauses.into_iter();
+        let Some((condition, true_branch)) = clauses.next() else {
+            return Err(error!(
+                "Cond",
+                Error::Other("Expected at least one clause with a test".to_string())
+            ));
+        };
 
         Ok(Self {
             condition,
             true_branch,
-            elif_branch,
+            elif_branch: clauses.collect(),
             false_branch,
         })
     }
@@ -46,11 +90,46 @@ impl TryFrom<&mut Parser> for If {
 impl ToString for If {
     fn to_string(&self) -> String {
         format!(
-            "if {} {{{}}}{} else {{{}}}",
+            "if {} {{{}}}{}{}",
             self.condition.to_string(),
             self.true_branch.to_string(),
             self.elif_branch.iter().fold(String::new(), |str, elif| format!("{str} else if {} {{{}}}", elif.0.to_string(), elif.1.to_string())),
-            self.false_branch.to_string()
+            match &self.false_branch {
+                Some(false_branch) => format!(" else {{{}}}", false_branch.to_string()),
+                None => String::new(),
+            }
         )
     }
 }
+
+/// (if let (Option->Some x) <- (stack.pop) x else 0)
+#[derive(Debug)]
+pub enum Condition {
+    Bool(Exp),
+    Let(Pattern, Exp),
+}
+
+impl TryFrom<&mut Parser> for Condition {
+    type Error = ParserError;
+
+    fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
+        if value.first() != Some(&Token::Keyword(Keywords::Let)) {
+            return Ok(Self::Bool(error!(Exp::try_from(&mut *value), "Condition")?));
+        }
+        value.pop_front();
+
+        let pattern = error!(Pattern::try_from(&mut *value), "Condition")?;
+        let _ = error!("Condition", value.pop_front(), [Token::Keyword(Keywords::RightArrow)])?;
+
+        Ok(Self::Let(pattern, error!(Exp::try_from(&mut *value), "Condition")?))
+    }
+}
+
+impl ToString for Condition {
+    fn to_string(&self) -> String {
+        match self {
+            Self::Bool(exp) => exp.to_string(),
+            Self::Let(pattern, exp) => format!("let {} = {}", pattern.to_string(), exp.to_string()),
+        }
+    }
+}
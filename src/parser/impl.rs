@@ -3,7 +3,8 @@ use crate::tokenizer::{Keywords, Token};
 use super::{
     error,
     file::FileOps,
-    r#type::Generic,
+    r#const::Const,
+    r#type::{GenericParams, Where},
     turbofish::TurboIden,
     Error, Parser, ParserError, ParserErrorStack,
 };
@@ -14,11 +15,12 @@ pub enum Impl {
     ///     (defun whatever()->i32 100)
     /// ])
     Trait {
-        lifetimes: Vec<String>,
-        generics: Vec<Generic>,
+        r#unsafe: bool,
+        params: GenericParams,
         r#trait: TurboIden,
         r#for: TurboIden,
-        /// Can only be Function, Attribute, Use, TypeAlias
+        r#where: Where,
+        /// Can only be Function, Attribute, Use, TypeAlias, Const
         body: Vec<FileOps>,
     },
     /// (impl Type [
@@ -26,10 +28,10 @@ pub enum Impl {
     ///         self/len)
     /// ])
     Funcs {
-        lifetimes: Vec<String>,
-        generics: Vec<Generic>,
+        params: GenericParams,
         r#for: TurboIden,
-        /// Can only be Function, Attribute, Use, TypeAlias
+        r#where: Where,
+        /// Can only be Function, Attribute, Use, TypeAlias, Const
         body: Vec<FileOps>,
     },
 }
@@ -39,23 +41,14 @@ impl TryFrom<&mut Parser> for Impl {
 
     fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
         let _ = error!("Impl", value.pop_front(), [Token::ParenOpen])?;
+        let r#unsafe = if matches!(value.first(), Some(Token::Identifier(iden)) if iden == "unsafe") {
+            value.pop_front();
+            true
+        } else {
+            false
+        };
         let _ = error!("Impl", value.pop_front(), [Token::Keyword(Keywords::Impl)])?;
-        let mut generics = vec![];
-        let mut lifetimes = vec![];
-
-        loop {
-            match value.first_err("Impl")? {
-                Token::Char(':') => generics.push(error!(Generic::try_from(&mut *value), "Impl")?),
-                Token::BackTick => {
-                    value.pop_front();
-                    match error!("Impl", value.pop_front(), [Token::Identifier(_)])? {
-                        Token::Identifier(iden) => lifetimes.push(iden),
-                        _ => unreachable!(),
-                    }
-                }
-                _ => break,
-            }
-        }
+        let params = error!(GenericParams::try_from(&mut *value), "Impl")?;
 
         let name = error!(TurboIden::try_from(&mut *value), "Impl")?;
         let for_trait = if value.first() == Some(&Token::Keyword(Keywords::LeftArrow)) {
@@ -65,6 +58,7 @@ impl TryFrom<&mut Parser> for Impl {
         } else {
             None
         };
+        let r#where = error!(Where::try_from(&mut *value), "Impl")?;
 
         let _ = error!("Impl", value.pop_front(), [Token::BracketOpen])?;
         let mut funcs = vec![];
@@ -81,12 +75,13 @@ impl TryFrom<&mut Parser> for Impl {
                 file @ (FileOps::Use(_)
                 | FileOps::Function(_)
                 | FileOps::TypeAlias(_)
+                | FileOps::Const(Const::Const { .. })
                 | FileOps::Attribute(_)) => file,
                 file => {
                     return Err(error!(
                         "Trait",
                         Error::Other(format!(
-                            "Expected function, use, type alias or attribute, got {file:#?}"
+                            "Expected function, use, type alias, const or attribute, got {file:#?}"
                         ))
                     ))
                 }
@@ -95,19 +90,25 @@ impl TryFrom<&mut Parser> for Impl {
 
         let _ = error!("Impl", value.pop_front(), [Token::ParenClose])?;
 
-        Ok(if let Some(r#trait) = for_trait {
+        Ok(if let Some(r#for) = for_trait {
             Self::Trait {
-                lifetimes,
-                generics,
-                r#trait,
-                r#for: name,
+                r#unsafe,
+                params,
+                r#trait: name,
+                r#for,
+                r#where,
                 body: funcs,
             }
+        } else if r#unsafe {
+            return Err(error!(
+                "Impl",
+                Error::Other(format!("Only trait impls can be unsafe, got impl for {}", name.to_string()))
+            ));
         } else {
             Self::Funcs {
-                lifetimes,
-                generics,
+                params,
                 r#for: name,
+                r#where,
                 body: funcs,
             }
         })
@@ -118,37 +119,23 @@ impl ToString for Impl {
     fn to_string(&self) -> String {
         match self {
             Self::Trait {
-                lifetimes,
-                generics,
+                r#unsafe,
+                params,
                 r#trait,
                 r#for,
+                r#where,
                 body,
             } => format!(
-                "impl{} {} for {} {{{}}}",
-                if generics.is_empty() && lifetimes.is_empty() {
-                    format!("")
+                "{}impl{} {} for {}{} {{{}}}",
+                if *r#unsafe {
+                    "unsafe ".to_string()
                 } else {
-                    format!("<{}>", {
-                        let lifetimes = lifetimes
-                            .iter()
-                            .map(|lifetime| format!("'{lifetime}"))
-                            .collect::<Vec<String>>()
-                            .join(", ");
-                        let generics = generics
-                            .iter()
-                            .map(|generic| generic.to_string())
-                            .collect::<Vec<String>>()
-                            .join(", ");
-
-                        if lifetimes.is_empty() {
-                            generics
-                        } else {
-                            lifetimes + ", " + &generics
-                        }
-                    })
+                    String::new()
                 },
+                params.to_string(),
                 r#trait.to_string(),
                 r#for.to_string(),
+                r#where.to_string(),
                 &if body.is_empty() {
                     format!("\n")
                 } else {
@@ -158,35 +145,15 @@ impl ToString for Impl {
                 }[1..]
             ),
             Self::Funcs {
-                lifetimes,
-                generics,
+                params,
                 r#for,
+                r#where,
                 body,
             } => format!(
-                "impl{} {} {{{}}}",
-                if generics.is_empty() && lifetimes.is_empty() {
-                    format!("")
-                } else {
-                    format!("<{}>", {
-                        let lifetimes = lifetimes
-                            .iter()
-                            .map(|lifetime| format!("'{lifetime}"))
-                            .collect::<Vec<String>>()
-                            .join(", ");
-                        let generics = generics
-                            .iter()
-                            .map(|generic| generic.to_string())
-                            .collect::<Vec<String>>()
-                            .join(", ");
-
-                        if lifetimes.is_empty() {
-                            generics
-                        } else {
-                            lifetimes + ", " + &generics
-                        }
-                    })
-                },
+                "impl{} {}{} {{{}}}",
+                params.to_string(),
                 r#for.to_string(),
+                r#where.to_string(),
                 &if body.is_empty() {
                     format!("\n")
                 } else {
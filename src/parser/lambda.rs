@@ -1,10 +1,15 @@
 use crate::tokenizer::{Token, Keywords};
 
-use super::{Parser, ParserError, ParserErrorStack, error, args::Args, exp::Exp};
+use super::{Parser, ParserError, ParserErrorStack, error, args::Args, exp::Exp, r#type::Type};
 
+/// (lambda move (x->i32 <a b>)->i32
+///     (+ x a b))
 #[derive(Debug)]
 pub struct Lambda {
+    r#async: bool,
+    r#move: bool,
     args: Args,
+    return_type: Option<Type>,
     body: Exp,
 }
 
@@ -14,16 +19,59 @@ impl TryFrom<&mut Parser> for Lambda {
     fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
         let _ = error!("Lambda", value.pop_front(), [Token::ParenOpen])?;
         let _ = error!("Lambda", value.pop_front(), [Token::Keyword(Keywords::Lambda)])?;
+        let r#async = if matches!(value.first(), Some(Token::Identifier(iden)) if iden == "async") {
+            value.pop_front();
+            true
+        } else {
+            false
+        };
+        let r#move = if matches!(value.first(), Some(Token::Identifier(iden)) if iden == "move") {
+            value.pop_front();
+            true
+        } else {
+            false
+        };
         let args = error!(Args::try_from(&mut *value), "Lambda")?;
+        let return_type = if value.first() == Some(&Token::Keyword(Keywords::LeftArrow)) {
+            value.pop_front();
+            Some(error!(Type::try_from(&mut *value), "Lambda")?)
+        } else {
+            None
+        };
         let body = error!(Exp::try_from(&mut *value), "Lambda")?;
         let _ = error!("Lambda", value.pop_front(), [Token::ParenClose])?;
 
-        Ok(Lambda { args, body })
+        Ok(Lambda {
+            r#async,
+            r#move,
+            args,
+            return_type,
+            body,
+        })
     }
 }
 
 impl ToString for Lambda {
     fn to_string(&self) -> String {
-        format!("{} {}", self.args.to_string(), self.body.to_string())
+        format!(
+            "{}{}{} {}",
+            if self.r#async {
+                "async ".to_string()
+            } else {
+                String::new()
+            },
+            if self.r#move {
+                "move ".to_string()
+            } else {
+                String::new()
+            },
+            self.args.to_string(),
+            match &self.return_type {
+                Some(return_type) => {
+                    format!("-> {} {{{}}}", return_type.to_string(), self.body.to_string())
+                }
+                None => self.body.to_string(),
+            }
+        )
     }
 }
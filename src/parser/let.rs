@@ -1,13 +1,14 @@
 use crate::tokenizer::{Keywords, Token};
 
-use super::{error, exp::Exp, Parser, ParserError, ParserErrorStack};
+use super::{error, exp::Exp, r#do::Binding, Parser, ParserError, ParserErrorStack};
 
 /// (let ((x 10)
-///       (y (+ 4 10)))
+///       (mut y->u64 (+ 4 10))
+///       (<a b> (pair)))
 ///     (* x y))
 #[derive(Debug)]
 pub struct Let {
-    vars: Vec<(String, Exp)>,
+    vars: Vec<(Binding, Exp)>,
     body: Exp,
 }
 
@@ -20,27 +21,24 @@ impl TryFrom<&mut Parser> for Let {
         let mut vars = vec![];
         let _ = error!("Let", value.pop_front(), [Token::ParenOpen])?;
 
-        match error!("Let", value.pop_front(), [Token::Identifier(_), Token::ParenOpen])? {
-            Token::Identifier(iden) => {
-                vars.push((iden, Exp::try_from(&mut *value)?));
-                let _ = error!("Let", value.pop_front(), [Token::ParenClose])?;
-            }
-            Token::ParenOpen => {
-                value.tokens.push_front(Token::ParenOpen);
-
-                loop {
-                    if error!("Let", value.pop_front(), [Token::ParenClose, Token::ParenOpen])? == Token::ParenClose {
-                        break;
-                    }
+        if value.first_err("Let")? == &Token::ParenOpen {
+            loop {
+                if error!("Let", value.pop_front(), [Token::ParenClose, Token::ParenOpen])? == Token::ParenClose {
+                    break;
+                }
 
-                    let iden = error!("Let", value);
-                    let exp = Exp::try_from(&mut *value)?;
-                    let _ = error!("Let", value.pop_front(), [Token::ParenClose])?;
+                let binding = error!(Binding::try_from(&mut *value), "Let")?;
+                let exp = error!(Exp::try_from(&mut *value), "Let")?;
+                let _ = error!("Let", value.pop_front(), [Token::ParenClose])?;
 
-                    vars.push((iden, exp));
-                }
+                vars.push((binding, exp));
             }
-            _ => unreachable!(),
+        } else {
+            vars.push((
+                error!(Binding::try_from(&mut *value), "Let")?,
+                error!(Exp::try_from(&mut *value), "Let")?,
+            ));
+            let _ = error!("Let", value.pop_front(), [Token::ParenClose])?;
         }
 
         let body = error!(Exp::try_from(&mut *value), "Let")?;
@@ -57,8 +55,8 @@ impl ToString for Let {
     fn to_string(&self) -> String {
         format!(
             "{{{}{}}}",
-            self.vars.iter().fold(String::new(), |str, (name, exp)| {
-                format!("{str}let {name} = {};\n", exp.to_string())
+            self.vars.iter().fold(String::new(), |str, (binding, exp)| {
+                format!("{str}let {} = {};\n", binding.to_string(), exp.to_string())
             }),
             self.body.to_string(),
         )
@@ -0,0 +1,55 @@
+use crate::tokenizer::{Keywords, Token};
+
+use super::{error, r#do::{label_to_string, DoActions}, Parser, ParserError, ParserErrorStack};
+
+/// (loop 'search
+///     if (= (idx items i) target)
+///         (break 'search i)
+///     i += 1)
+#[derive(Debug)]
+pub struct Loop {
+    label: Option<String>,
+    body: Vec<DoActions>,
+}
+
+impl TryFrom<&mut Parser> for Loop {
+    type Error = ParserError;
+
+    fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
+        let _ = error!("Loop", value.pop_front(), [Token::ParenOpen])?;
+        let _ = error!("Loop", value.pop_front(), [Token::Keyword(Keywords::Loop)])?;
+        let label = if let Some(&Token::Lifetime(_)) = value.first() {
+            let Some(Token::Lifetime(label)) = value.pop_front() else {
+                unreachable!()
+            };
+
+            Some(label)
+        } else {
+            None
+        };
+        let mut body = vec![];
+
+        loop {
+            let peek = value.first_err("Loop")?;
+
+            if peek == &Token::ParenClose {
+                value.pop_front();
+                break Ok(Self { label, body });
+            }
+
+            body.push(error!(DoActions::try_from(&mut *value), "Loop")?)
+        }
+    }
+}
+
+impl ToString for Loop {
+    fn to_string(&self) -> String {
+        format!(
+            "{}loop {{{}}}",
+            label_to_string(&self.label),
+            self.body.iter().fold(String::new(), |str, action| {
+                format!("{str}\n{}", action.to_string())
+            })
+        )
+    }
+}
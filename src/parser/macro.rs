@@ -0,0 +1,447 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::tokenizer::{Keywords, Token};
+
+use super::{error, Error, Parser, ParserError, ParserErrorStack};
+
+/// How deep expansions can nest, stops macros that expand into themselves,
+/// the same as rustc's default recursion limit
+const MAX_DEPTH: usize = 128;
+
+/// (defmacro otherwise (cond ..body)
+///     `(do if (not ,cond) (do ,@body)))
+///
+/// Every template after the params is quasiquoted, ,x puts in an argument,
+/// ,@body splices in the rest of the args and x# is renamed to x__n, where n
+/// is unique to each expansion, names like that are reserved for macros
+#[derive(Debug)]
+pub struct Macro {
+    name: String,
+    params: Vec<String>,
+    rest: Option<String>,
+    template: Vec<Token>,
+}
+
+impl TryFrom<&mut Parser> for Macro {
+    type Error = ParserError;
+
+    fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
+        let _ = error!("Macro", value.pop_front(), [Token::ParenOpen])?;
+        let defmacro = error!("Macro", value);
+
+        if &defmacro != "defmacro" {
+            return Err(error!(
+                "Macro",
+                Error::Other(format!("Expected defmacro, got {defmacro:?}"))
+            ));
+        }
+
+        let name = error!("Macro", value);
+        let _ = error!("Macro", value.pop_front(), [Token::ParenOpen])?;
+        let mut params = vec![];
+        let mut rest = None;
+
+        loop {
+            match error!(
+                "Macro",
+                value.pop_front(),
+                [Token::Identifier(_), Token::DoubleDot, Token::ParenClose]
+            )? {
+                Token::ParenClose => break,
+                Token::DoubleDot => {
+                    rest = Some(error!("Macro", value));
+                    let _ = error!("Macro", value.pop_front(), [Token::ParenClose])?;
+                    break;
+                }
+                Token::Identifier(param) if params.contains(&param) => {
+                    return Err(error!(
+                        "Macro",
+                        Error::Other(format!("Duplicate param {param} in macro {name}"))
+                    ))
+                }
+                Token::Identifier(param) => params.push(param),
+                _ => unreachable!(),
+            }
+        }
+
+        let mut template = vec![];
+
+        loop {
+            if value.first_err("Macro")? == &Token::ParenClose {
+                value.pop_front();
+                break;
+            }
+
+            let _ = error!("Macro", value.pop_front(), [Token::BackTick])?;
+            let Some(end) = datum_end(&value.tokens, 0) else {
+                return Err(error!(
+                    "Macro",
+                    Error::Other(format!("Unbalanced template in macro {name}"))
+                ));
+            };
+
+            template.extend(value.tokens.drain(..end));
+        }
+
+        let r#macro = Self {
+            name,
+            params,
+            rest,
+            template,
+        };
+
+        if let Err(err) = r#macro.validate() {
+            return Err(error!(
+                "Macro",
+                Error::Other(format!("{err}\nin {}", r#macro.signature()))
+            ));
+        }
+
+        Ok(r#macro)
+    }
+}
+
+impl Macro {
+    /// Checks the unquotes up front, so mistakes point at the definition even if it's never called
+    fn validate(&self) -> Result<(), String> {
+        if self.template.is_empty() {
+            return Err("Expected at least one quasiquoted template".to_string());
+        }
+
+        let mut tokens = self.template.iter();
+
+        while let Some(token) = tokens.next() {
+            match token {
+                Token::BackTick => return Err("Nested quasiquotes aren't supported".to_string()),
+                Token::Char(',') => {
+                    let (splice, param) = match tokens.next() {
+                        Some(Token::Char('@')) => (true, tokens.next()),
+                        param => (false, param),
+                    };
+
+                    match param {
+                        Some(Token::Identifier(param)) if self.params.contains(param) => {}
+                        Some(Token::Identifier(param)) if self.rest.as_ref() == Some(param) => {
+                            if !splice {
+                                return Err(format!("The rest param {param} has to be spliced with ,@{param}"));
+                            }
+                        }
+                        Some(token) => return Err(format!("Unquoted {} isn't a param", token.to_string())),
+                        None => return Err("Expected a param after the unquote".to_string()),
+                    }
+                }
+                Token::Identifier(iden) if is_gensym(iden) => {
+                    return Err(format!("{iden} is reserved for renamed names, use {}# instead", gensym_name(iden)))
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn expand(&self, mut args: Vec<Vec<Token>>, expansion: usize) -> Result<Vec<Token>, String> {
+        if args.len() < self.params.len() || (self.rest.is_none() && args.len() > self.params.len()) {
+            return Err(format!(
+                "Expected {}{} args, got {}",
+                if self.rest.is_some() { "at least " } else { "" },
+                self.params.len(),
+                args.len()
+            ));
+        }
+
+        let rest = args.split_off(self.params.len()).concat();
+        let args = self.params.iter().zip(args).collect::<HashMap<&String, Vec<Token>>>();
+        let mut expanded = vec![];
+        let mut tokens = self.template.iter();
+
+        while let Some(token) = tokens.next() {
+            match token {
+                Token::Char(',') => {
+                    let param = match tokens.next() {
+                        Some(Token::Char('@')) => tokens.next(),
+                        param => param,
+                    };
+
+                    match param {
+                        Some(Token::Identifier(param)) if self.rest.as_ref() == Some(param) => {
+                            expanded.extend(rest.iter().cloned())
+                        }
+                        Some(Token::Identifier(param)) => expanded.extend(args[param].iter().cloned()),
+                        _ => unreachable!("checked by validate"),
+                    }
+                }
+                Token::Identifier(iden) if iden.ends_with('#') => expanded.push(Token::Identifier(
+                    format!("{}__{expansion}", &iden[..iden.len() - 1]),
+                )),
+                token => expanded.push(token.clone()),
+            }
+        }
+
+        Ok(expanded)
+    }
+
+    /// How the macro was defined, for errors
+    fn signature(&self) -> String {
+        format!(
+            "(defmacro {} ({}))",
+            self.name,
+            self.params
+                .iter()
+                .cloned()
+                .chain(self.rest.iter().map(|rest| format!("..{rest}")))
+                .collect::<Vec<String>>()
+                .join(" ")
+        )
+    }
+}
+
+/// Takes the macro definitions out of the file and expands their calls,
+/// the result of an expansion is expanded again so macros can use each other
+pub(crate) fn expand(value: &mut Parser) -> Result<(), ParserError> {
+    let mut macros = HashMap::new();
+    let mut tokens = VecDeque::new();
+
+    while !value.tokens.is_empty() {
+        if value.first() == Some(&Token::ParenOpen)
+            && matches!(value.nth(1), Some(Token::Identifier(iden)) if iden == "defmacro")
+        {
+            let r#macro = error!(Macro::try_from(&mut *value), "Expand")?;
+
+            if macros.contains_key(&r#macro.name) {
+                return Err(error!(
+                    "Expand",
+                    Error::Other(format!("Macro {} is defined twice", r#macro.name))
+                ));
+            }
+
+            macros.insert(r#macro.name.clone(), r#macro);
+        } else {
+            let end = datum_end(&value.tokens, 0).unwrap_or(value.tokens.len());
+            tokens.extend(value.tokens.drain(..end));
+        }
+    }
+
+    // Checked before expanding, after that the renamed names are in the tokens too
+    if let Some(iden) = tokens.iter().find_map(|token| match token {
+        Token::Identifier(iden) if is_gensym(iden) => Some(iden),
+        _ => None,
+    }) {
+        return Err(error!(
+            "Expand",
+            Error::Other(format!("{iden} is reserved for names renamed in macro templates"))
+        ));
+    }
+
+    value.tokens = expand_calls(&tokens, &macros, 0, &mut 0)?;
+
+    Ok(())
+}
+
+/// Expands the macro calls in `tokens`, each expansion is expanded again one level deeper
+fn expand_calls(
+    tokens: &VecDeque<Token>,
+    macros: &HashMap<String, Macro>,
+    depth: usize,
+    expansions: &mut usize,
+) -> Result<VecDeque<Token>, ParserError> {
+    let mut expanded = VecDeque::new();
+    let mut nth = 0;
+
+    while nth < tokens.len() {
+        match (&tokens[nth], tokens.get(nth + 1)) {
+            (Token::ParenOpen, Some(Token::Identifier(iden))) if iden == "defmacro" => {
+                return Err(error!(
+                    "Expand",
+                    Error::Other("Macros can only be defined at the top level".to_string())
+                ))
+            }
+            (Token::ParenOpen, Some(Token::Identifier(iden))) if macros.contains_key(iden) => {
+                let r#macro = &macros[iden];
+                let mut args = vec![];
+                let mut end = nth + 2;
+
+                while tokens.get(end) != Some(&Token::ParenClose) {
+                    let Some(arg_end) = datum_end(tokens, end) else {
+                        return Err(error!(
+                            "Expand",
+                            Error::Other(format!("Unbalanced call to macro {}", r#macro.name))
+                        ));
+                    };
+
+                    args.push(tokens.range(end..arg_end).cloned().collect());
+                    end = arg_end;
+                }
+                end += 1;
+                *expansions += 1;
+
+                let call = match r#macro.expand(args, *expansions) {
+                    Ok(_) if depth >= MAX_DEPTH => Err(format!(
+                        "Expansions are nested more than {MAX_DEPTH} deep, the macro probably expands into itself"
+                    )),
+                    call => call,
+                }
+                .map_err(|err| {
+                    error!(
+                        "Expand",
+                        Error::Other(format!(
+                            "{err}\nin {}\ncalled as {}",
+                            r#macro.signature(),
+                            source(tokens.range(nth..end))
+                        ))
+                    )
+                })?;
+
+                // Not wrapped in the error stack, that would add a frame for every level
+                expanded.extend(expand_calls(&call.into(), macros, depth + 1, expansions)?);
+                nth = end;
+            }
+            (Token::Identifier(iden), _) if iden.ends_with('#') => {
+                return Err(error!(
+                    "Expand",
+                    Error::Other(format!("{iden} can only be used in a macro template"))
+                ))
+            }
+            (token, _) => {
+                expanded.push_back(token.clone());
+                nth += 1;
+            }
+        }
+    }
+
+    Ok(expanded)
+}
+
+/// x__1, the name x# gets in the first expansion
+fn is_gensym(iden: &str) -> bool {
+    iden.rsplit_once("__").is_some_and(|(name, n)| {
+        !name.is_empty() && !n.is_empty() && n.chars().all(|char| char.is_ascii_digit())
+    })
+}
+
+fn gensym_name(iden: &str) -> &str {
+    iden.rsplit_once("__").map_or(iden, |(name, _)| name)
+}
+
+/// The index right after the datum starting at `start`, which is a token or a group
+/// with its prefixes and the paths, fields, methods, generics and ranges chained to it
+fn datum_end(tokens: &VecDeque<Token>, start: usize) -> Option<usize> {
+    let mut nth = start;
+
+    loop {
+        match tokens.get(nth)? {
+            Token::Ref | Token::Keyword(Keywords::Deref | Keywords::Mut) | Token::BackTick => {}
+            Token::Char(':' | '#' | ',' | '@') => {}
+            _ => break,
+        }
+        nth += 1;
+    }
+
+    nth = atom_end(tokens, nth)?;
+
+    loop {
+        nth = match tokens.get(nth) {
+            Some(Token::Keyword(Keywords::LeftArrow) | Token::Char('.')) => atom_end(tokens, nth + 1)?,
+            Some(Token::Slash) if matches!(tokens.get(nth + 1), Some(Token::Identifier(_) | Token::Literal(_))) => {
+                nth + 2
+            }
+            Some(Token::Char('?')) => nth + 1,
+            Some(Token::DoubleDot) => {
+                let nth = nth + 1 + (tokens.get(nth + 1) == Some(&Token::Char('='))) as usize;
+
+                match tokens.get(nth)? {
+                    Token::ParenClose | Token::BracketClose | Token::CurlyClose => nth,
+                    _ => atom_end(tokens, nth)?,
+                }
+            }
+            Some(Token::Keyword(Keywords::TurboStart)) => {
+                let mut depth = 0;
+                let mut nth = nth;
+
+                loop {
+                    match tokens.get(nth)? {
+                        Token::Keyword(Keywords::TurboStart) | Token::AngleBracketOpen => depth += 1,
+                        Token::AngleBracketClose => depth -= 1,
+                        _ => {}
+                    }
+                    nth += 1;
+
+                    if depth == 0 {
+                        break nth;
+                    }
+                }
+            }
+            _ => break Some(nth),
+        }
+    }
+}
+
+/// A single token, a balanced (), [] or {} group or a tuple like <1 (f x)>
+fn atom_end(tokens: &VecDeque<Token>, start: usize) -> Option<usize> {
+    if tokens.get(start)? == &Token::AngleBracketOpen {
+        let mut nth = start + 1;
+
+        while tokens.get(nth)? != &Token::AngleBracketClose {
+            nth = datum_end(tokens, nth)?;
+        }
+
+        return Some(nth + 1);
+    }
+
+    let mut depth = 0;
+    let mut nth = start;
+
+    loop {
+        match tokens.get(nth)? {
+            Token::ParenOpen | Token::BracketOpen | Token::CurlyOpen => depth += 1,
+            Token::ParenClose | Token::BracketClose | Token::CurlyClose if depth == 0 => return None,
+            Token::ParenClose | Token::BracketClose | Token::CurlyClose => depth -= 1,
+            _ => {}
+        }
+        nth += 1;
+
+        if depth == 0 {
+            break Some(nth);
+        }
+    }
+}
+
+fn source<'a>(tokens: impl Iterator<Item = &'a Token>) -> String {
+    let mut str = String::new();
+    let mut glue = true;
+
+    for token in tokens {
+        let attached = matches!(
+            token,
+            Token::ParenClose
+                | Token::BracketClose
+                | Token::CurlyClose
+                | Token::AngleBracketClose
+                | Token::Slash
+                | Token::DoubleDot
+                | Token::Char('.' | '?')
+                | Token::Keyword(Keywords::LeftArrow | Keywords::TurboStart)
+        );
+
+        if !glue && !attached {
+            str.push(' ');
+        }
+
+        str += &token.to_string();
+        glue = matches!(
+            token,
+            Token::ParenOpen
+                | Token::BracketOpen
+                | Token::CurlyOpen
+                | Token::AngleBracketOpen
+                | Token::Ref
+                | Token::Slash
+                | Token::DoubleDot
+                | Token::BackTick
+                | Token::Char('.' | ':' | '#' | ',' | '@')
+                | Token::Keyword(Keywords::LeftArrow | Keywords::TurboStart | Keywords::Deref)
+        );
+    }
+
+    str
+}
@@ -3,7 +3,7 @@ use either::Either;
 use crate::tokenizer::{Keywords, Literals, Token};
 
 use super::{
-    error, exp::Exp, r#type::NamespacedType, range::Range, Parser, ParserError, ParserErrorStack, Error,
+    error, exp::Exp, path::Path, range::Range, Parser, ParserError, ParserErrorStack, Error,
 };
 
 #[derive(Debug)]
@@ -103,11 +103,11 @@ pub enum Pattern {
     Literal(Literals),
     Touple(Vec<Self>),
     Array(Vec<Self>),
-    Capture(String, Box<Self>),
+    Binding(PatternBinding<Self>),
     Range(Range),
-    Enum(NamespacedType),
-    EnumVars(NamespacedType, Vec<Self>),
-    Struct(NamespacedType, Vec<Either<(String, Self), String>>),
+    Enum(Path),
+    EnumVars(Path, Vec<Self>),
+    Struct(Path, Vec<Either<(String, Self), String>>),
     Rest,
     Or(Vec<Self>),
 }
@@ -116,39 +116,52 @@ impl TryFrom<&mut Parser> for Pattern {
     type Error = ParserError;
 
     fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
+        if Path::is_qualified(value, 0) {
+            return Ok(Self::Enum(error!(Path::try_from(&mut *value), "Pattern")?));
+        }
+
+        if PatternBinding::<Self>::is_next(value) {
+            return Ok(Self::Binding(error!(PatternBinding::try_from(&mut *value), "Pattern")?));
+        }
+
         let next = error!("Pattern", value.pop_front(), [Token::Identifier(_), Token::Literal(_), Token::BracketOpen, Token::AngleBracketOpen, Token::DoubleDot, Token::ParenOpen])?;
         Ok(match next {
-            Token::Identifier(iden) if matches!(value.first(), Some(&Token::Char('@'))) => {
-                value.pop_front();
-                Self::Capture(
-                    iden,
-                    Box::new(error!(Self::try_from(&mut *value), "Pattern")?),
-                )
-            }
             iden @ Token::Identifier(_)
-                if value.first() == Some(&Token::Keyword(Keywords::LeftArrow)) =>
+                if matches!(
+                    value.first(),
+                    Some(&Token::Keyword(Keywords::LeftArrow | Keywords::TurboStart))
+                ) =>
             {
                 value.tokens.push_front(iden);
-                Self::Enum(error!(NamespacedType::try_from(&mut *value), "Pattern")?)
+                Self::Enum(error!(Path::try_from(&mut *value), "Pattern")?)
             }
             Token::Identifier(iden) => Self::Variable(iden),
             lit @ Token::Literal(Literals::Int(..) | Literals::Char(_)) if value.first() == Some(&Token::DoubleDot) => {
                 value.tokens.push_front(lit);
 
-                Self::Range(match error!(Range::try_from(&mut *value), "Pattern")? {
-                    Range::Normal(start, end)
-                        if matches!(start, Exp::Literal(_)) && matches!(end, Exp::Literal(_)) =>
-                    {
+                // Exp already reads the whole range from its start
+                Self::Range(match error!(Exp::try_from(&mut *value), "Pattern")? {
+                    Exp::Range(range) => match *range {
                         Range::Normal(start, end)
-                    }
-                    Range::Inclusive(start, end)
-                        if matches!(start, Exp::Literal(_)) && matches!(end, Exp::Literal(_)) =>
-                    {
+                            if matches!(start, Exp::Literal(_)) && matches!(end, Exp::Literal(_)) =>
+                        {
+                            Range::Normal(start, end)
+                        }
                         Range::Inclusive(start, end)
-                    }
-                    Range::Infinite(start) if matches!(start, Exp::Literal(_)) => {
-                        Range::Infinite(start)
-                    }
+                            if matches!(start, Exp::Literal(_)) && matches!(end, Exp::Literal(_)) =>
+                        {
+                            Range::Inclusive(start, end)
+                        }
+                        Range::Infinite(start) if matches!(start, Exp::Literal(_)) => {
+                            Range::Infinite(start)
+                        }
+                        range => {
+                            return Err(error!(
+                                "Pattern",
+                                Error::Other(format!("Expected range to consist of literals, got {range:#?}"))
+                            ))
+                        }
+                    },
                     range => {
                         return Err(error!(
                             "Pattern",
@@ -211,7 +224,7 @@ impl TryFrom<&mut Parser> for Pattern {
                     }
                     iden @ Token::Identifier(_) if value.first() != Some(&Token::ParenClose) => {
                         value.tokens.push_front(iden);
-                        let namespace = error!(NamespacedType::try_from(&mut *value), "Pattern")?;
+                        let namespace = error!(Path::try_from(&mut *value), "Pattern")?;
 
                         return Ok(
                             match value.first_err("Pattern")?
@@ -303,7 +316,7 @@ impl ToString for Pattern {
                     })
                 }[2..]
             ),
-            Self::Capture(capture, pat) => format!("{capture}@{}", pat.to_string()),
+            Self::Binding(binding) => binding.to_string(),
             Self::Range(range) => format!("{}", range.to_string()),
             Self::Enum(path) => format!("{}", path.to_string()),
             Self::EnumVars(path, pats) => format!(
@@ -349,3 +362,78 @@ impl ToString for Pattern {
         }
     }
 }
+
+/// The part of the grammar shared by patterns and destructuring
+#[derive(Debug)]
+pub enum PatternBinding<P> {
+    /// &x
+    Ref(Box<P>),
+    /// &mut x
+    RefMut(Box<P>),
+    /// ref x
+    ByRef(String),
+    /// ref mut x
+    ByRefMut(String),
+    /// mut x
+    ByMut(String),
+    /// rest @ ..
+    Capture(String, Box<P>),
+}
+
+impl<P> PatternBinding<P> {
+    pub(crate) fn is_next(value: &Parser) -> bool {
+        match value.first() {
+            Some(Token::Ref | Token::Keyword(Keywords::Mut)) => true,
+            Some(Token::Identifier(iden)) if iden == "ref" => matches!(
+                value.nth(1),
+                Some(Token::Identifier(_) | Token::Keyword(Keywords::Mut))
+            ),
+            Some(Token::Identifier(_)) => value.nth(1) == Some(&Token::Char('@')),
+            _ => false,
+        }
+    }
+}
+
+impl<P> TryFrom<&mut Parser> for PatternBinding<P>
+where
+    P: for<'a> TryFrom<&'a mut Parser, Error = ParserError>,
+{
+    type Error = ParserError;
+
+    fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
+        match error!("PatternBinding", value.pop_front(), [Token::Ref, Token::Keyword(Keywords::Mut), Token::Identifier(_)])? {
+            Token::Ref if value.first() == Some(&Token::Keyword(Keywords::Mut)) => {
+                value.pop_front();
+                Ok(Self::RefMut(Box::new(error!(P::try_from(&mut *value), "PatternBinding")?)))
+            }
+            Token::Ref => Ok(Self::Ref(Box::new(error!(P::try_from(&mut *value), "PatternBinding")?))),
+            Token::Keyword(Keywords::Mut) => Ok(Self::ByMut(error!("PatternBinding", value))),
+            Token::Identifier(iden) if iden == "ref" => {
+                if value.first() == Some(&Token::Keyword(Keywords::Mut)) {
+                    value.pop_front();
+                    Ok(Self::ByRefMut(error!("PatternBinding", value)))
+                } else {
+                    Ok(Self::ByRef(error!("PatternBinding", value)))
+                }
+            }
+            Token::Identifier(iden) => {
+                let _ = error!("PatternBinding", value.pop_front(), [Token::Char('@')])?;
+                Ok(Self::Capture(iden, Box::new(error!(P::try_from(&mut *value), "PatternBinding")?)))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl<P: ToString> ToString for PatternBinding<P> {
+    fn to_string(&self) -> String {
+        match self {
+            Self::Ref(pat) => format!("&{}", pat.to_string()),
+            Self::RefMut(pat) => format!("&mut {}", pat.to_string()),
+            Self::ByRef(name) => format!("ref {name}"),
+            Self::ByRefMut(name) => format!("ref mut {name}"),
+            Self::ByMut(name) => format!("mut {name}"),
+            Self::Capture(name, pat) => format!("{name}@{}", pat.to_string()),
+        }
+    }
+}
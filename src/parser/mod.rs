@@ -1,17 +1,22 @@
 pub mod args;
 pub mod r#as;
 pub mod attribute;
+pub mod r#const;
 pub mod defun;
 pub mod r#do;
 pub mod r#enum;
 pub mod exp;
+pub mod r#extern;
 pub mod file;
 pub mod r#if;
 pub mod r#impl;
 pub mod lambda;
+pub mod r#macro;
 pub mod r#let;
+pub mod r#loop;
 pub mod r#match;
 pub mod module;
+pub mod path;
 pub mod range;
 pub mod r#struct;
 mod tests;
@@ -156,7 +161,7 @@ impl Parser {
         }
     }
 
-    fn nth(&mut self, nth: usize) -> Option<&Token> {
+    fn nth(&self, nth: usize) -> Option<&Token> {
         if self.tokens.len() > nth {
             Some(self.tokens.index(nth))
         } else {
@@ -164,7 +169,7 @@ impl Parser {
         }
     }
 
-    fn nth_err(&mut self, nth: usize, func: &'static str) -> Result<&Token, ParserError> {
+    fn nth_err(&self, nth: usize, func: &'static str) -> Result<&Token, ParserError> {
         if self.tokens.len() > nth {
             Ok(self.tokens.index(nth))
         } else {
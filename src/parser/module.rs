@@ -17,7 +17,7 @@ impl TryFrom<&mut Parser> for Mod {
 
     fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
         let _ = error!("Mod", value.pop_front(), [Token::ParenOpen])?;
-        let scope = Scope::try_from(&mut *value).unwrap();
+        let scope = error!(Scope::try_from(&mut *value), "Mod")?;
         let _ = error!("Mod", value.pop_front(), [Token::Keyword(Keywords::Mod)])?;
         let name = error!("Mod", value);
 
@@ -0,0 +1,152 @@
+use crate::tokenizer::{Keywords, Token};
+
+use super::{error, r#type::Type, Error, Parser, ParserError, ParserErrorStack};
+
+/// std->collections->HashMap<k v>->new
+/// (as :t Default)->default
+/// (as Self Iterator)->Item
+#[derive(Debug)]
+pub struct Path(Vec<PathSegment>);
+
+#[derive(Debug)]
+pub enum PathSegment {
+    Name(String, Vec<Type>),
+    /// (as :t Trait) or just (as :t), only as the first segment
+    Qualified(Box<Type>, Option<Box<Type>>),
+}
+
+impl Path {
+    /// Checks for a qualified self starting at `nth`, the closing paren of which is followed by ->
+    pub(crate) fn is_qualified(value: &Parser, nth: usize) -> bool {
+        if value.nth(nth) != Some(&Token::ParenOpen)
+            || value.nth(nth + 1) != Some(&Token::Keyword(Keywords::As))
+        {
+            return false;
+        }
+
+        let mut depth = 0;
+        let mut nth = nth;
+
+        loop {
+            match value.nth(nth) {
+                Some(Token::ParenOpen) => depth += 1,
+                Some(Token::ParenClose) => {
+                    depth -= 1;
+
+                    if depth == 0 {
+                        break value.nth(nth + 1) == Some(&Token::Keyword(Keywords::LeftArrow));
+                    }
+                }
+                Some(_) => {}
+                None => break false,
+            }
+
+            nth += 1;
+        }
+    }
+
+    /// Adds generic args to the last segment, for types like (std->rc->Rc :t)
+    pub(crate) fn push_generics(&mut self, types: Vec<Type>) {
+        if let Some(PathSegment::Name(_, generics)) = self.0.last_mut() {
+            generics.extend(types);
+        }
+    }
+
+    /// Types don't need the turbofish before generic args
+    pub(crate) fn to_type_string(&self) -> String {
+        self.emit(false)
+    }
+
+    fn emit(&self, turbofish: bool) -> String {
+        self.0
+            .iter()
+            .map(|segment| segment.emit(turbofish))
+            .collect::<Vec<String>>()
+            .join("::")
+    }
+}
+
+impl TryFrom<&mut Parser> for Path {
+    type Error = ParserError;
+
+    fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
+        let mut segments = vec![];
+
+        loop {
+            if value.first() == Some(&Token::ParenOpen) {
+                if !segments.is_empty() {
+                    return Err(error!(
+                        "Path",
+                        Error::Other("A qualified self can only start a path".to_string())
+                    ));
+                }
+
+                value.pop_front();
+                let _ = error!("Path", value.pop_front(), [Token::Keyword(Keywords::As)])?;
+                let r#type = error!(Type::try_from(&mut *value), "Path")?;
+                let r#trait = if value.first() == Some(&Token::ParenClose) {
+                    None
+                } else {
+                    Some(Box::new(error!(Type::try_from(&mut *value), "Path")?))
+                };
+                let _ = error!("Path", value.pop_front(), [Token::ParenClose])?;
+                let _ = error!("Path", value.first(), [Token::Keyword(Keywords::LeftArrow)])?;
+
+                segments.push(PathSegment::Qualified(Box::new(r#type), r#trait));
+            } else {
+                let name = error!("Path", value);
+                let mut generics = vec![];
+
+                if value.first() == Some(&Token::Keyword(Keywords::TurboStart)) {
+                    value.pop_front();
+
+                    loop {
+                        let peek = value.first_err("Path")?;
+
+                        if peek == &Token::AngleBracketClose {
+                            value.pop_front();
+                            break;
+                        }
+
+                        generics.push(error!(Type::try_from(&mut *value), "Path")?);
+                    }
+                }
+
+                segments.push(PathSegment::Name(name, generics));
+            }
+
+            if value.first() == Some(&Token::Keyword(Keywords::LeftArrow)) {
+                value.pop_front();
+            } else {
+                break Ok(Self(segments));
+            }
+        }
+    }
+}
+
+impl ToString for Path {
+    fn to_string(&self) -> String {
+        self.emit(true)
+    }
+}
+
+impl PathSegment {
+    fn emit(&self, turbofish: bool) -> String {
+        match self {
+            Self::Name(name, generics) if generics.is_empty() => name.to_string(),
+            Self::Name(name, generics) => format!(
+                "{name}{}<{}>",
+                if turbofish { "::" } else { "" },
+                generics
+                    .iter()
+                    .map(|generic| generic.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Self::Qualified(r#type, None) => format!("<{}>", r#type.to_string()),
+            Self::Qualified(r#type, Some(r#trait)) => {
+                format!("<{} as {}>", r#type.to_string(), r#trait.to_string())
+            }
+        }
+    }
+}
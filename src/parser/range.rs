@@ -7,6 +7,9 @@ pub enum Range {
     Normal(Exp, Exp),
     Inclusive(Exp, Exp),
     Infinite(Exp),
+    To(Exp),
+    ToInclusive(Exp),
+    Full,
 }
 
 impl TryFrom<&mut Parser> for Range {
@@ -45,6 +48,9 @@ impl ToString for Range {
             Self::Normal(start, end) => format!("({}..{})", start.to_string(), end.to_string()),
             Self::Inclusive(start, end) => format!("({}..={})", start.to_string(), end.to_string()),
             Self::Infinite(start) => format!("({}..)", start.to_string()),
+            Self::To(end) => format!("(..{})", end.to_string()),
+            Self::ToInclusive(end) => format!("(..={})", end.to_string()),
+            Self::Full => "(..)".to_string(),
         }
     }
 }
@@ -2,21 +2,34 @@ use crate::tokenizer::{Keywords, Token};
 
 use super::{
     attribute::Attribute,
+    defun::Scope,
     error,
-    r#type::{Generic, Type},
-    Parser, ParserError, ParserErrorStack,
+    r#type::{GenericParams, Type, Where},
+    Error, Parser, ParserError, ParserErrorStack,
 };
 
 #[derive(Debug)]
 pub enum Struct {
     Touple {
+        scope: Scope,
         name: String,
-        generics: Vec<Generic>,
-        types: Vec<Type>,
+        params: GenericParams,
+        r#where: Where,
+        types: Vec<(Scope, Type)>,
     },
     Normal {
+        scope: Scope,
         name: String,
-        generics: Vec<Generic>,
+        params: GenericParams,
+        r#where: Where,
+        fields: StructFields,
+    },
+    /// (union Value {int->i64 ptr->*const u8})
+    Union {
+        scope: Scope,
+        name: String,
+        params: GenericParams,
+        r#where: Where,
         fields: StructFields,
     },
 }
@@ -26,22 +39,49 @@ impl TryFrom<&mut Parser> for Struct {
 
     fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
         let _ = error!("Struct", value.pop_front(), [Token::ParenOpen])?;
-        let _ = error!(
+        let scope = error!(Scope::try_from(&mut *value), "Struct")?;
+        let union = match error!(
             "Struct",
             value.pop_front(),
-            [Token::Keyword(Keywords::Struct)]
-        )?;
+            [Token::Keyword(Keywords::Struct), Token::Identifier(_)]
+        )? {
+            Token::Identifier(iden) if iden == "union" => true,
+            Token::Identifier(iden) => {
+                return Err(error!(
+                    "Struct",
+                    Error::Other(format!("Expected struct or union, got {iden:?}"))
+                ))
+            }
+            _ => false,
+        };
         let name = error!("Struct", value);
-        let mut generics = vec![];
+        let params = error!(GenericParams::try_from(&mut *value), "Struct")?;
 
-        loop {
-            let peek = value.first_err("Struct")?;
+        let r#where = error!(Where::try_from(&mut *value), "Struct")?;
 
-            if let Token::Char(':') = peek {
-                generics.push(error!(Generic::try_from(&mut *value), "Struct")?)
+        if union {
+            let fields = if value.first() == Some(&Token::CurlyOpen) {
+                error!(StructFields::try_from(&mut *value), "Struct")?
             } else {
-                break;
+                StructFields(vec![])
+            };
+
+            if fields.0.is_empty() {
+                return Err(error!(
+                    "Struct",
+                    Error::Other(format!("Union {name} needs at least one named field"))
+                ));
             }
+
+            let _ = error!("Struct", value.pop_front(), [Token::ParenClose])?;
+
+            return Ok(Self::Union {
+                scope,
+                name,
+                params,
+                r#where,
+                fields,
+            });
         }
 
         if value.first() == Some(&Token::CurlyOpen) {
@@ -49,8 +89,10 @@ impl TryFrom<&mut Parser> for Struct {
             let _ = error!("Struct", value.pop_front(), [Token::ParenClose])?;
 
             Ok(Struct::Normal {
+                scope,
                 name,
-                generics,
+                params,
+                r#where,
                 fields,
             })
         } else {
@@ -62,13 +104,18 @@ impl TryFrom<&mut Parser> for Struct {
                 if peek == &Token::ParenClose {
                     value.pop_front();
                     break Ok(Self::Touple {
+                        scope,
                         name,
-                        generics,
+                        params,
+                        r#where,
                         types,
                     });
                 }
 
-                types.push(error!(Type::try_from(&mut *value), "Struct")?)
+                types.push((
+                    error!(Scope::try_from(&mut *value), "Struct")?,
+                    error!(Type::try_from(&mut *value), "Struct")?,
+                ))
             }
         }
     }
@@ -78,53 +125,60 @@ impl ToString for Struct {
     fn to_string(&self) -> String {
         match self {
             Self::Touple {
+                scope,
                 name,
-                generics,
+                params,
+                r#where,
                 types,
             } => {
                 format!(
-                    "struct {name}{}({});",
-                    if !generics.is_empty() {
-                        format!(
-                            "<{}>",
-                            &generics.iter().fold(String::new(), |str, generic| {
-                                format!("{str}, {}", generic.to_string())
-                            })[2..]
-                        )
-                    } else {
-                        format!("")
-                    },
+                    "{}struct {name}{}({}){};",
+                    scope.to_string(),
+                    params.to_string(),
                     &if types.is_empty() {
                         format!(", ")
                     } else {
                         format!(
                             "{}",
-                            &types.iter().fold(String::new(), |str, r#type| format!(
-                                "{str}, {}",
+                            &types.iter().fold(String::new(), |str, (scope, r#type)| format!(
+                                "{str}, {}{}",
+                                scope.to_string(),
                                 r#type.to_string()
                             ))
                         )
-                    }[2..]
+                    }[2..],
+                    r#where.to_string()
                 )
             }
             Self::Normal {
+                scope,
                 name,
-                generics,
+                params,
+                r#where,
                 fields,
             } => {
                 format!(
-                    "struct {}{} {}",
+                    "{}struct {}{}{} {}",
+                    scope.to_string(),
                     name,
-                    if !generics.is_empty() {
-                        format!(
-                            "<{}>",
-                            &generics.iter().fold(String::new(), |str, generic| {
-                                format!("{str}, {}", generic.to_string())
-                            })[2..]
-                        )
-                    } else {
-                        format!("")
-                    },
+                    params.to_string(),
+                    r#where.to_string(),
+                    fields.to_string()
+                )
+            }
+            Self::Union {
+                scope,
+                name,
+                params,
+                r#where,
+                fields,
+            } => {
+                format!(
+                    "{}union {}{}{} {}",
+                    scope.to_string(),
+                    name,
+                    params.to_string(),
+                    r#where.to_string(),
                     fields.to_string()
                 )
             }
@@ -175,6 +229,7 @@ impl ToString for StructFields {
 #[derive(Debug)]
 struct StructField {
     attr: Option<Attribute>,
+    scope: Scope,
     name: String,
     r#type: Type,
 }
@@ -191,6 +246,7 @@ impl TryFrom<&mut Parser> for StructField {
             None
         };
 
+        let scope = error!(Scope::try_from(&mut *value), "StructField")?;
         let name = error!("StructField", value);
         let _ = error!(
             "StructField",
@@ -200,6 +256,7 @@ impl TryFrom<&mut Parser> for StructField {
 
         Ok(Self {
             attr,
+            scope,
             name,
             r#type: error!(Type::try_from(&mut *value), "StructField")?,
         })
@@ -209,12 +266,13 @@ impl TryFrom<&mut Parser> for StructField {
 impl ToString for StructField {
     fn to_string(&self) -> String {
         format!(
-            "{}{}: {}",
+            "{}{}{}: {}",
             if let Some(attr) = &self.attr {
                 format!("{}\n", attr.to_string())
             } else {
                 format!("")
             },
+            self.scope.to_string(),
             self.name,
             self.r#type.to_string()
         )
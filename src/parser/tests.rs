@@ -1,8 +1,8 @@
 #[cfg(test)]
 mod tests {
     use crate::parser::{
-        defun::Defun, exp::Exp, lambda::Lambda, r#enum::Enum, r#if::If, r#let::Let, r#match::Match,
-        r#struct::Struct, r#use::Use,
+        defun::Defun, exp::Exp, file::File, r#const::Const, lambda::Lambda, r#enum::Enum, r#if::If, r#impl::Impl, r#let::Let, r#match::Match,
+        r#struct::Struct, r#trait::Trait, r#type::{Type, TypeAlias}, r#use::Use,
     };
 
     macro_rules! snapshot {
@@ -69,4 +69,36 @@ mod tests {
     snapshot!(test_lambda_rust, Lambda::try_from, "lambda.lt", rust);
     snapshot!(test_use, Use::try_from, "use.lt");
     snapshot!(test_use_rust, Use::try_from, "use.lt", rust);
+    snapshot!(test_index, Exp::try_from, "index.lt");
+    snapshot!(test_index_rust, Exp::try_from, "index.lt", rust);
+    snapshot!(test_assignment, Exp::try_from, "assignment.lt");
+    snapshot!(test_assignment_rust, Exp::try_from, "assignment.lt", rust);
+    snapshot!(test_do, Exp::try_from, "do.lt");
+    snapshot!(test_do_rust, Exp::try_from, "do.lt", rust);
+    snapshot!(test_async, Exp::try_from, "async.lt");
+    snapshot!(test_async_rust, Exp::try_from, "async.lt", rust);
+    snapshot!(test_unsafe, Exp::try_from, "unsafe.lt");
+    snapshot!(test_unsafe_rust, Exp::try_from, "unsafe.lt", rust);
+    snapshot!(test_const, Const::try_from, "const.lt");
+    snapshot!(test_const_rust, Const::try_from, "const.lt", rust);
+    snapshot!(test_trait, Trait::try_from, "trait.lt");
+    snapshot!(test_trait_rust, Trait::try_from, "trait.lt", rust);
+    snapshot!(test_impl, Impl::try_from, "impl.lt");
+    snapshot!(test_impl_rust, Impl::try_from, "impl.lt", rust);
+    snapshot!(test_type, Type::try_from, "type.lt");
+    snapshot!(test_type_rust, Type::try_from, "type.lt", rust);
+    snapshot!(test_type_alias, TypeAlias::try_from, "type_alias.lt");
+    snapshot!(test_type_alias_rust, TypeAlias::try_from, "type_alias.lt", rust);
+    snapshot!(test_path, Exp::try_from, "path.lt");
+    snapshot!(test_path_rust, Exp::try_from, "path.lt", rust);
+    snapshot!(test_thread, Exp::try_from, "thread.lt");
+    snapshot!(test_thread_rust, Exp::try_from, "thread.lt", rust);
+    snapshot!(test_cond, Exp::try_from, "cond.lt");
+    snapshot!(test_cond_rust, Exp::try_from, "cond.lt", rust);
+    snapshot!(test_macro, File::try_from, "macro.lt");
+    snapshot!(test_macro_rust, File::try_from, "macro.lt", rust);
+    snapshot!(test_not, Exp::try_from, "not.lt");
+    snapshot!(test_not_rust, Exp::try_from, "not.lt", rust);
+    snapshot!(test_extern, File::try_from, "extern.lt");
+    snapshot!(test_extern_rust, File::try_from, "extern.lt", rust);
 }
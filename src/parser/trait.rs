@@ -1,15 +1,27 @@
 use crate::tokenizer::{Keywords, Token};
 
 use super::{
+    defun::Scope,
     error,
-    turbofish::TurboIden,
+    r#const::Const,
+    r#type::{Constraints, GenericParams, Where},
     file::FileOps,
     Parser, ParserError, ParserErrorStack, Error,
 };
 
+/// (trait Container /Debug Clone/ :t [
+///     (type Item /Debug/)
+///     (defun get (&self i->usize)->(Option self/Item))
+/// ])
 #[derive(Debug)]
 pub struct Trait {
-    name: TurboIden,
+    scope: Scope,
+    r#unsafe: bool,
+    name: String,
+    params: GenericParams,
+    supertraits: Option<Constraints>,
+    r#where: Where,
+    /// Can only be Function, Attribute, Use, TypeAlias, Const
     body: Vec<FileOps>,
 }
 
@@ -18,8 +30,23 @@ impl TryFrom<&mut Parser> for Trait {
 
     fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
         let _ = error!("Trait", value.pop_front(), [Token::ParenOpen])?;
+        let scope = error!(Scope::try_from(&mut *value), "Trait")?;
+        let r#unsafe = if matches!(value.first(), Some(Token::Identifier(iden)) if iden == "unsafe") {
+            value.pop_front();
+            true
+        } else {
+            false
+        };
         let _ = error!("Trait", value.pop_front(), [Token::Keyword(Keywords::Trait)])?;
-        let name = error!(TurboIden::try_from(&mut *value), "Trait")?;
+        let name = error!("Trait", value);
+        // Right after the name, so they aren't mistaken for the constraints of a generic
+        let supertraits = if value.first() == Some(&Token::Slash) {
+            Some(error!(Constraints::try_from(&mut *value), "Trait")?)
+        } else {
+            None
+        };
+        let params = error!(GenericParams::try_from(&mut *value), "Trait")?;
+        let r#where = error!(Where::try_from(&mut *value), "Trait")?;
         let mut body = vec![];
         let _ = error!("Trait", value.pop_front(), [Token::BracketOpen])?;
 
@@ -31,15 +58,22 @@ impl TryFrom<&mut Parser> for Trait {
                 break;
             }
 
+            // Parsed here since only trait consts can leave out the value
+            if matches!(value.nth(Scope::skip(value, 1)), Some(Token::Identifier(iden)) if iden == "const" || iden == "static") {
+                body.push(FileOps::Const(error!(Const::associated(&mut *value), "Trait")?));
+                continue;
+            }
+
             body.push(match error!(FileOps::try_from(&mut *value), "Trait")? {
                 file @ (FileOps::Use(_)
                 | FileOps::Function(_)
                 | FileOps::TypeAlias(_)
+                | FileOps::Const(_)
                 | FileOps::Attribute(_)) => file,
                 file => {
                     return Err(error!(
                         "Trait",
-                        Error::Other(format!("Expected function, use, type alias or attribute, got {file:#?}"))
+                        Error::Other(format!("Expected function, use, type alias, const or attribute, got {file:#?}"))
                     ))
                 }
             })
@@ -47,15 +81,36 @@ impl TryFrom<&mut Parser> for Trait {
 
         let _ = error!("Trait", value.pop_front(), [Token::ParenClose])?;
 
-        Ok(Self { name, body })
+        Ok(Self {
+            scope,
+            r#unsafe,
+            name,
+            params,
+            supertraits,
+            r#where,
+            body,
+        })
     }
 }
 
 impl ToString for Trait {
     fn to_string(&self) -> String {
         format!(
-            "trait {} {{{}}}",
-            self.name.to_string(),
+            "{}{}trait {}{}{}{} {{{}}}",
+            self.scope.to_string(),
+            if self.r#unsafe {
+                "unsafe ".to_string()
+            } else {
+                String::new()
+            },
+            self.name,
+            self.params.to_string(),
+            if let Some(supertraits) = &self.supertraits {
+                format!(": {}", supertraits.to_string())
+            } else {
+                String::new()
+            },
+            self.r#where.to_string(),
             &if self.body.is_empty() {
                 format!("\n")
             } else {
@@ -31,8 +31,9 @@ impl ToString for TurboIden {
     }
 }
 
+/// collect<(HashMap _ _)>
 #[derive(Debug)]
-pub struct TurboFish(String, Type);
+pub struct TurboFish(String, Vec<Type>);
 
 impl TryFrom<&mut Parser> for TurboFish {
     type Error = ParserError;
@@ -44,15 +45,31 @@ impl TryFrom<&mut Parser> for TurboFish {
             value.pop_front(),
             [Token::Keyword(Keywords::TurboStart)]
         )?;
-        let r#type = error!(Type::try_from(&mut *value), "TurboFish")?;
-        let _ = error!("TurboFish", value.pop_front(), [Token::AngleBracketClose])?;
+        let mut types = vec![];
 
-        Ok(Self(var, r#type))
+        loop {
+            let peek = value.first_err("TurboFish")?;
+
+            if peek == &Token::AngleBracketClose {
+                value.pop_front();
+                break Ok(Self(var, types));
+            }
+
+            types.push(error!(Type::try_from(&mut *value), "TurboFish")?);
+        }
     }
 }
 
 impl ToString for TurboFish {
     fn to_string(&self) -> String {
-        format!("{}::<{}>", self.0, self.1.to_string())
+        format!(
+            "{}::<{}>",
+            self.0,
+            self.1
+                .iter()
+                .map(|r#type| r#type.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
     }
 }
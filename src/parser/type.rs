@@ -1,17 +1,40 @@
-use crate::tokenizer::{BuiltinTypes, Int, Keywords, Literals, Token};
+use crate::tokenizer::{BuiltinTypes, Keywords, Literals, Token};
 
-use super::{error, turbofish::TurboIden, Parser, ParserError, ParserErrorStack};
+use super::{defun::Scope, error, exp::Exp, path::Path, Error, Parser, ParserError, ParserErrorStack};
 
 #[derive(Debug)]
 pub enum Type {
-    Ref(Option<Lifetimes>, Box<Type>),
-    RefMut(Option<Lifetimes>, Box<Type>),
+    /// &'a str
+    Ref(Option<String>, Box<Type>),
+    /// &'a mut str
+    RefMut(Option<String>, Box<Type>),
+    /// *const u8
+    RawPointer(Box<Type>),
+    /// *mut u8
+    RawPointerMut(Box<Type>),
     Builtin(BuiltinTypes),
     Generic(Generic),
     Custom(String),
     Complex(String, Vec<Type>),
-    Array(Box<Type>, Option<usize>),
+    /// std->io->Error, (std->rc->Rc :t), (as Self Iterator)->Item
+    Path(Path),
+    /// [u8] or [u8; (* 4 SIZE)]
+    Array(Box<Type>, Option<Box<Exp>>),
+    /// Const generic argument: (Buf 16)
+    Const(Box<Exp>),
+    /// Lifetime argument: (Parser 'a)
+    Lifetime(String),
     Touple(Vec<Type>),
+    /// Associated type binding inside of generic args: (Iterator (= Item u8))
+    Binding(String, Box<Type>),
+    /// Closure trait: (Fn (i32 i32)->i32)
+    FnTrait(String, Vec<Type>, Option<Box<Type>>),
+    /// Function pointer: (fn (i32)->i32)
+    FnPointer(Vec<Type>, Option<Box<Type>>),
+    /// (dyn (Fn (u8)->bool) Send 'a)
+    Dyn(Vec<Bound>),
+    /// (impl (Iterator (= Item u8)))
+    Impl(Vec<Bound>),
     SelfA,
     SelfT,
 }
@@ -20,12 +43,22 @@ impl TryFrom<&mut Parser> for Type {
     type Error = ParserError;
 
     fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
+        if Path::is_qualified(value, 0)
+            || (matches!(value.first(), Some(Token::Identifier(_)))
+                && value.nth(1) == Some(&Token::Keyword(Keywords::LeftArrow)))
+        {
+            return Ok(Type::Path(error!(Path::try_from(&mut *value), "Type")?));
+        }
+
         match error!(
             "Type",
             value.pop_front(),
             [
                 Token::Ref,
+                Token::Keyword(Keywords::Deref),
                 Token::Type(_),
+                Token::Literal(Literals::Int(_)),
+                Token::Lifetime(_),
                 Token::Char(':'),
                 Token::Identifier(_),
                 Token::BracketOpen,
@@ -33,34 +66,52 @@ impl TryFrom<&mut Parser> for Type {
                 Token::ParenOpen
             ]
         )? {
-            Token::Ref if value.first() == Some(&Token::Slash) => {
-                let lifetimes = error!(Lifetimes::try_from(&mut *value), "Type")?;
+            Token::Ref => {
+                let lifetime = if let Some(&Token::Lifetime(_)) = value.first() {
+                    let Some(Token::Lifetime(lifetime)) = value.pop_front() else {
+                        unreachable!()
+                    };
+
+                    Some(lifetime)
+                } else {
+                    None
+                };
 
                 if let Some(&Token::Keyword(Keywords::Mut)) = value.first() {
                     value.pop_front();
                     Ok(Self::RefMut(
-                        Some(lifetimes),
+                        lifetime,
                         Box::new(error!(Type::try_from(&mut *value), "Type")?),
                     ))
                 } else {
                     Ok(Self::Ref(
-                        Some(lifetimes),
+                        lifetime,
                         Box::new(error!(Type::try_from(&mut *value), "Type")?),
                     ))
                 }
             }
-            Token::Ref if value.first() == Some(&Token::Keyword(Keywords::Mut)) => {
-                value.pop_front();
-                Ok(Type::RefMut(
-                    None,
-                    Box::new(error!(Type::try_from(&mut *value), "Type")?),
-                ))
+            Token::Keyword(Keywords::Deref) => {
+                match error!(
+                    "Type",
+                    value.pop_front(),
+                    [Token::Identifier(_), Token::Keyword(Keywords::Mut)]
+                )? {
+                    Token::Identifier(iden) if &iden == "const" => Ok(Type::RawPointer(Box::new(
+                        error!(Type::try_from(&mut *value), "Type")?,
+                    ))),
+                    Token::Keyword(Keywords::Mut) => Ok(Type::RawPointerMut(Box::new(error!(
+                        Type::try_from(&mut *value),
+                        "Type"
+                    )?))),
+                    token => Err(error!(
+                        "Type",
+                        Error::Other(format!("Expected const or mut after *, got {token:#?}"))
+                    )),
+                }
             }
-            Token::Ref => Ok(Type::Ref(
-                None,
-                Box::new(error!(Type::try_from(&mut *value), "Type")?),
-            )),
             Token::Type(builtin) => Ok(Type::Builtin(builtin)),
+            Token::Literal(literal) => Ok(Type::Const(Box::new(Exp::Literal(literal)))),
+            Token::Lifetime(lifetime) => Ok(Type::Lifetime(lifetime)),
             Token::Char(':') => {
                 value.tokens.push_front(Token::Char(':'));
                 Ok(Type::Generic(error!(
@@ -81,15 +132,10 @@ impl TryFrom<&mut Parser> for Type {
                 )? {
                     Token::BracketClose => Ok(Type::Array(r#type, None)),
                     Token::Char(';') => {
-                        let Token::Literal(Literals::Int(Int(false, len))) =
-                            error!("Type", value.pop_front(), [Token::Literal(Literals::Int(Int(false, _)))])?
-                        else {
-                            unreachable!()
-                        };
-
+                        let len = error!(Exp::try_from(&mut *value), "Type")?;
                         let _ = error!("Type", value.pop_front(), [Token::BracketClose])?;
 
-                        Ok(Type::Array(r#type, Some(len as usize)))
+                        Ok(Type::Array(r#type, Some(Box::new(len))))
                     }
                     _ => unreachable!(),
                 }
@@ -111,7 +157,71 @@ impl TryFrom<&mut Parser> for Type {
                 Ok(Type::Touple(types))
             }
             Token::ParenOpen => {
-                match error!("Type", value.pop_front(), [Token::Identifier(_)])? {
+                match error!(
+                    "Type",
+                    value.pop_front(),
+                    [Token::Identifier(_), Token::Char('='), Token::Keyword(Keywords::Impl)]
+                )? {
+                    Token::Identifier(iden) if &iden == "dyn" => {
+                        Ok(Type::Dyn(error!(Bound::list(&mut *value), "Type")?))
+                    }
+                    Token::Keyword(Keywords::Impl) => {
+                        Ok(Type::Impl(error!(Bound::list(&mut *value), "Type")?))
+                    }
+                    Token::Char('=') => {
+                        let name = error!("Type", value);
+                        let r#type = error!(Type::try_from(&mut *value), "Type")?;
+                        let _ = error!("Type", value.pop_front(), [Token::ParenClose])?;
+
+                        Ok(Type::Binding(name, Box::new(r#type)))
+                    }
+                    Token::Identifier(iden)
+                        if matches!(&iden[..], "fn" | "Fn" | "FnMut" | "FnOnce")
+                            && value.first() == Some(&Token::ParenOpen) =>
+                    {
+                        value.pop_front();
+                        let mut args = vec![];
+
+                        loop {
+                            let peek = value.first_err("Type")?;
+
+                            if peek == &Token::ParenClose {
+                                value.pop_front();
+                                break;
+                            }
+
+                            args.push(error!(Type::try_from(&mut *value), "Type")?);
+                        }
+
+                        let return_type = if value.first() == Some(&Token::Keyword(Keywords::LeftArrow)) {
+                            value.pop_front();
+                            Some(Box::new(error!(Type::try_from(&mut *value), "Type")?))
+                        } else {
+                            None
+                        };
+                        let _ = error!("Type", value.pop_front(), [Token::ParenClose])?;
+
+                        if &iden == "fn" {
+                            Ok(Type::FnPointer(args, return_type))
+                        } else {
+                            Ok(Type::FnTrait(iden, args, return_type))
+                        }
+                    }
+                    Token::Identifier(iden)
+                        if value.first() == Some(&Token::Keyword(Keywords::LeftArrow)) =>
+                    {
+                        value.tokens.push_front(Token::Identifier(iden));
+                        let mut path = error!(Path::try_from(&mut *value), "Type")?;
+                        let mut types = vec![];
+
+                        while value.first_err("Type")? != &Token::ParenClose {
+                            types.push(error!(Type::try_from(&mut *value), "Type")?);
+                        }
+                        value.pop_front();
+                        path.push_generics(types);
+
+                        Ok(Type::Path(path))
+                    }
                     Token::Identifier(iden) => {
                         let mut types = vec![];
 
@@ -133,14 +243,16 @@ impl TryFrom<&mut Parser> for Type {
 impl ToString for Type {
     fn to_string(&self) -> String {
         match self {
-            Type::Ref(None, r#type) => format!("&{}", r#type.to_string()),
-            Type::Ref(Some(lifetimes), r#type) => {
-                format!("&{}{}", lifetimes.to_string(), r#type.to_string())
+            Type::Ref(None, r#type) => format!("&{}", r#type.pointee_string()),
+            Type::Ref(Some(lifetime), r#type) => {
+                format!("&'{lifetime} {}", r#type.pointee_string())
             }
-            Type::RefMut(None, r#type) => format!("&mut {}", r#type.to_string()),
-            Type::RefMut(Some(lifetimes), r#type) => {
-                format!("&{}mut {}", lifetimes.to_string(), r#type.to_string())
+            Type::RefMut(None, r#type) => format!("&mut {}", r#type.pointee_string()),
+            Type::RefMut(Some(lifetime), r#type) => {
+                format!("&'{lifetime} mut {}", r#type.pointee_string())
             }
+            Type::RawPointer(r#type) => format!("*const {}", r#type.pointee_string()),
+            Type::RawPointerMut(r#type) => format!("*mut {}", r#type.pointee_string()),
             Type::Builtin(builtin) => builtin.to_string(),
             Type::Generic(generic) => generic.to_string(),
             Type::Custom(name) => name.to_string(),
@@ -150,8 +262,13 @@ impl ToString for Type {
                     format!("{str}, {}", r#type.to_string())
                 })[2..]
             ),
+            Type::Path(path) => path.to_type_string(),
             Type::Array(r#type, None) => format!("[{}]", r#type.to_string()),
-            Type::Array(r#type, Some(len)) => format!("[{}; {len}]", r#type.to_string()),
+            Type::Array(r#type, Some(len)) => {
+                format!("[{}; {}]", r#type.to_string(), len.to_string())
+            }
+            Type::Const(exp) => exp.to_string(),
+            Type::Lifetime(lifetime) => format!("'{lifetime}"),
             Type::Touple(types) => format!(
                 "({})",
                 &if types.is_empty() {
@@ -162,170 +279,396 @@ impl ToString for Type {
                     })
                 }[2..]
             ),
+            Type::Binding(name, r#type) => format!("{name} = {}", r#type.to_string()),
+            Type::FnTrait(name, args, return_type) => format!(
+                "{name}({}){}",
+                args.iter()
+                    .map(|arg| arg.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                if let Some(return_type) = return_type {
+                    format!(" -> {}", return_type.to_string())
+                } else {
+                    String::new()
+                }
+            ),
+            Type::FnPointer(args, return_type) => format!(
+                "fn({}){}",
+                args.iter()
+                    .map(|arg| arg.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                if let Some(return_type) = return_type {
+                    format!(" -> {}", return_type.to_string())
+                } else {
+                    String::new()
+                }
+            ),
+            Type::Dyn(bounds) => format!("dyn {}", Bound::join(bounds)),
+            Type::Impl(bounds) => format!("impl {}", Bound::join(bounds)),
             Self::SelfT => format!("Self"),
             Self::SelfA => format!("self"),
         }
     }
 }
 
+impl Type {
+    /// Multiple bounds behind a reference or pointer need parens: &(dyn Read + Send)
+    fn pointee_string(&self) -> String {
+        match self {
+            Self::Dyn(bounds) | Self::Impl(bounds) if bounds.len() > 1 => {
+                format!("({})", self.to_string())
+            }
+            _ => self.to_string(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Generic {
+    Constrained {
+        name: String,
+        constraints: Constraints,
+    },
+    /// :N->usize
+    Const {
+        name: String,
+        r#type: Box<Type>,
+    },
+    Use(String),
+}
+
+impl TryFrom<&mut Parser> for Generic {
+    type Error = ParserError;
+
+    fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
+        let _ = error!("Generic", value.pop_front(), [Token::Char(':')])?;
+        let name = error!("Generic", value);
+
+        if value.first() == Some(&Token::Slash) {
+            Ok(Self::Constrained {
+                name,
+                constraints: error!(Constraints::try_from(&mut *value), "Generic")?,
+            })
+        } else if value.first() == Some(&Token::Keyword(Keywords::LeftArrow)) {
+            value.pop_front();
+
+            Ok(Self::Const {
+                name,
+                r#type: Box::new(error!(Type::try_from(&mut *value), "Generic")?),
+            })
+        } else {
+            Ok(Self::Use(name))
+        }
+    }
+}
+
+impl ToString for Generic {
+    fn to_string(&self) -> String {
+        match self {
+            Self::Constrained { name, constraints } => {
+                format!("{name}: {}", constraints.to_string())
+            }
+            Self::Const { name, r#type } => format!("const {name}: {}", r#type.to_string()),
+            Self::Use(name) => format!("{name}"),
+        }
+    }
+}
+
+/// Lifetime and generic parameters of an item: 'a 'b :t/Clone/ :N->usize
 #[derive(Debug)]
-pub struct Lifetimes(Vec<String>);
+pub struct GenericParams {
+    lifetimes: Vec<String>,
+    generics: Vec<Generic>,
+}
+
+impl GenericParams {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.lifetimes.is_empty() && self.generics.is_empty()
+    }
+}
 
-impl TryFrom<&mut Parser> for Lifetimes {
+impl TryFrom<&mut Parser> for GenericParams {
     type Error = ParserError;
 
+    /// Parses parameters while there are any, so it can return empty ones
     fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
-        let _ = error!("Lifetimes", value.pop_front(), [Token::Slash])?;
         let mut lifetimes = vec![];
+        let mut generics = vec![];
+
+        loop {
+            match value.first() {
+                Some(Token::Char(':')) => {
+                    generics.push(error!(Generic::try_from(&mut *value), "GenericParams")?)
+                }
+                Some(Token::Lifetime(_)) => {
+                    let Some(Token::Lifetime(lifetime)) = value.pop_front() else {
+                        unreachable!()
+                    };
+
+                    if !generics.is_empty() {
+                        return Err(error!(
+                            "GenericParams",
+                            Error::Other(format!(
+                                "Lifetime '{lifetime} should be declared before the generics"
+                            ))
+                        ));
+                    }
+
+                    lifetimes.push(lifetime)
+                }
+                _ => break Ok(Self { lifetimes, generics }),
+            }
+        }
+    }
+}
+
+impl ToString for GenericParams {
+    fn to_string(&self) -> String {
+        if self.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "<{}>",
+                self.lifetimes
+                    .iter()
+                    .map(|lifetime| format!("'{lifetime}"))
+                    .chain(self.generics.iter().map(|generic| generic.to_string()))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )
+        }
+    }
+}
+
+/// /Clone (AsRef str) (Iterator (= Item u8)) (Fn (i32)->i32) 'a ?Sized/
+#[derive(Debug)]
+pub struct Constraints(Vec<Bound>);
+
+impl TryFrom<&mut Parser> for Constraints {
+    type Error = ParserError;
+
+    fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
+        let _ = error!("Constraints", value.pop_front(), [Token::Slash])?;
+        let mut constraints = vec![];
 
         loop {
-            let peek = value.first_err("Lifetimes")?;
+            let peek = value.first_err("Constraints")?;
 
             if peek == &Token::Slash {
                 value.pop_front();
-                break;
+                return Ok(Self(constraints));
             }
 
-            lifetimes.push(error!("Lifetimes", value));
+            constraints.push(error!(Bound::try_from(&mut *value), "Constraints")?);
         }
-
-        Ok(Self(lifetimes))
     }
 }
 
-impl ToString for Lifetimes {
+impl ToString for Constraints {
     fn to_string(&self) -> String {
-        if self.0.is_empty() {
-            format!("")
-        } else {
-            self.0
-                .iter()
-                .fold(String::new(), |str, lifetime| format!("{str}'{lifetime} "))
-        }
+        Bound::join(&self.0)
     }
 }
 
 #[derive(Debug)]
-pub enum NamespacedType {
-    Space(TurboIden, Box<NamespacedType>),
-    Str(TurboIden),
+pub enum Bound {
+    Type(Type),
+    Lifetime(String),
+    /// ?Sized
+    Maybe(String),
 }
 
-impl TryFrom<&mut Parser> for NamespacedType {
+impl TryFrom<&mut Parser> for Bound {
     type Error = ParserError;
 
     fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
-        let name = error!(TurboIden::try_from(&mut *value), "NamespacedType")?;
+        match value.first_err("Bound")? {
+            Token::Lifetime(_) => {
+                let Some(Token::Lifetime(lifetime)) = value.pop_front() else {
+                    unreachable!()
+                };
 
-        Ok(
-            if value.first() == Some(&Token::Keyword(Keywords::LeftArrow)) {
+                Ok(Self::Lifetime(lifetime))
+            }
+            Token::Char('?') => {
                 value.pop_front();
-                Self::Space(
-                    name,
-                    Box::new(error!(Self::try_from(&mut *value), "NamespacedType")?),
-                )
-            } else {
-                Self::Str(name)
-            },
-        )
+
+                Ok(Self::Maybe(error!("Bound", value)))
+            }
+            _ => Ok(Self::Type(error!(Type::try_from(&mut *value), "Bound")?)),
+        }
+    }
+}
+
+impl Bound {
+    /// Bounds until the closing paren, which is consumed
+    fn list(value: &mut Parser) -> Result<Vec<Self>, ParserError> {
+        let mut bounds = vec![];
+
+        loop {
+            let peek = value.first_err("Bound")?;
+
+            if peek == &Token::ParenClose {
+                value.pop_front();
+                break;
+            }
+
+            bounds.push(error!(Bound::try_from(&mut *value), "Bound")?);
+        }
+
+        if bounds.is_empty() {
+            return Err(error!(
+                "Bound",
+                Error::Other("Expected at least one bound".to_string())
+            ));
+        }
+
+        Ok(bounds)
+    }
+
+    fn join(bounds: &[Self]) -> String {
+        bounds
+            .iter()
+            .map(|bound| bound.to_string())
+            .collect::<Vec<String>>()
+            .join(" + ")
     }
 }
 
-impl ToString for NamespacedType {
+impl ToString for Bound {
     fn to_string(&self) -> String {
         match self {
-            Self::Space(name, namespaces) => {
-                format!("{}::{}", name.to_string(), namespaces.to_string())
-            }
-            Self::Str(name) => format!("{}", name.to_string()),
+            Self::Type(r#type) => r#type.to_string(),
+            Self::Lifetime(lifetime) => format!("'{lifetime}"),
+            Self::Maybe(name) => format!("?{name}"),
         }
     }
 }
 
+/// (where :t/Clone/ (Vec :t)/Debug/ 'a/'b/)
 #[derive(Debug)]
-pub enum Generic {
-    Constrained {
-        name: String,
-        constraints: Constraints,
-    },
-    Use(String),
+pub struct Where(Vec<WherePredicate>);
+
+impl Where {
+    pub(crate) fn is_next(value: &Parser) -> bool {
+        value.first() == Some(&Token::ParenOpen)
+            && matches!(value.nth(1), Some(Token::Identifier(iden)) if iden == "where")
+    }
 }
 
-impl TryFrom<&mut Parser> for Generic {
+impl TryFrom<&mut Parser> for Where {
     type Error = ParserError;
 
+    /// Parses a where clause if there is one, otherwise returns an empty one
     fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
-        let _ = error!("Generic", value.pop_front(), [Token::Char(':')])?;
-        let name = error!("Generic", value);
+        if !Self::is_next(value) {
+            return Ok(Self(vec![]));
+        }
 
-        if value.first() == Some(&Token::Slash) {
-            Ok(Self::Constrained {
-                name,
-                constraints: error!(Constraints::try_from(&mut *value), "Generic")?,
-            })
-        } else {
-            Ok(Self::Use(name))
+        value.pop_front();
+        value.pop_front();
+        let mut predicates = vec![];
+
+        loop {
+            let peek = value.first_err("Where")?;
+
+            if peek == &Token::ParenClose {
+                value.pop_front();
+                break Ok(Self(predicates));
+            }
+
+            predicates.push(error!(WherePredicate::try_from(&mut *value), "Where")?);
         }
     }
 }
 
-impl ToString for Generic {
+impl ToString for Where {
     fn to_string(&self) -> String {
-        match self {
-            Self::Constrained { name, constraints } => {
-                format!("{name}: {}", constraints.to_string())
-            }
-            Self::Use(name) => format!("{name}"),
+        if self.0.is_empty() {
+            String::new()
+        } else {
+            format!(
+                " where {}",
+                self.0
+                    .iter()
+                    .map(|predicate| predicate.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )
         }
     }
 }
 
 #[derive(Debug)]
-pub struct Constraints(Vec<String>);
+pub enum WherePredicate {
+    Generic(String, Constraints),
+    Type(Type, Constraints),
+    Lifetime(String, Constraints),
+}
 
-impl TryFrom<&mut Parser> for Constraints {
+impl TryFrom<&mut Parser> for WherePredicate {
     type Error = ParserError;
 
     fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
-        let _ = error!("Constraints", value.pop_front(), [Token::Slash])?;
-        let mut constraints = vec![];
-
-        loop {
-            let next = error!(
-                "Constraints",
-                value.pop_front(),
-                [Token::Slash, Token::Identifier(_)]
-            )?;
-            match next {
-                Token::Slash => {
-                    return Ok(Self(constraints));
-                }
-                Token::Identifier(iden) => constraints.push(iden),
-                _ => unreachable!(),
+        match value.first_err("WherePredicate")? {
+            Token::Char(':') => match error!(Generic::try_from(&mut *value), "WherePredicate")? {
+                Generic::Constrained { name, constraints } => Ok(Self::Generic(name, constraints)),
+                Generic::Use(name) | Generic::Const { name, .. } => Err(error!(
+                    "WherePredicate",
+                    Error::Other(format!("Expected constraints for generic {name}"))
+                )),
+            },
+            Token::Lifetime(_) => {
+                let Some(Token::Lifetime(lifetime)) = value.pop_front() else {
+                    unreachable!()
+                };
+
+                Ok(Self::Lifetime(
+                    lifetime,
+                    error!(Constraints::try_from(&mut *value), "WherePredicate")?,
+                ))
             }
+            _ => Ok(Self::Type(
+                error!(Type::try_from(&mut *value), "WherePredicate")?,
+                error!(Constraints::try_from(&mut *value), "WherePredicate")?,
+            )),
         }
     }
 }
 
-impl ToString for Constraints {
+impl ToString for WherePredicate {
     fn to_string(&self) -> String {
-        format!(
-            "{}",
-            &if self.0.is_empty() {
-                format!(" + ")
-            } else {
-                self.0.iter().fold(String::new(), |str, constraint| {
-                    format!("{str} + {}", constraint.to_string())
-                })
-            }[3..]
-        )
+        match self {
+            Self::Generic(name, constraints) => format!("{name}: {}", constraints.to_string()),
+            Self::Type(r#type, constraints) => {
+                format!("{}: {}", r#type.to_string(), constraints.to_string())
+            }
+            Self::Lifetime(lifetime, constraints) => {
+                format!("'{lifetime}: {}", constraints.to_string())
+            }
+        }
     }
 }
 
 #[derive(Debug)]
 pub enum TypeAlias {
-    Alias { name: String, r#type: Type },
+    /// (pub type Pair 'a :t = <&'a :t :t>)
+    /// The = is only needed with params, (type Output :t) aliases the generic
+    Alias {
+        scope: Scope,
+        name: String,
+        params: GenericParams,
+        r#type: Type,
+    },
     Def(String),
+    /// Associated type in a trait, with bounds and an optional default
+    /// (type Item /Debug Clone/ u8)
+    Associated {
+        name: String,
+        constraints: Constraints,
+        default: Option<Type>,
+    },
 }
 
 impl TryFrom<&mut Parser> for TypeAlias {
@@ -333,6 +676,7 @@ impl TryFrom<&mut Parser> for TypeAlias {
 
     fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
         let _ = error!("TypeALias", value.pop_front(), [Token::ParenOpen])?;
+        let scope = error!(Scope::try_from(&mut *value), "TypeAlias")?;
         let _ = error!(
             "TypeALias",
             value.pop_front(),
@@ -340,25 +684,92 @@ impl TryFrom<&mut Parser> for TypeAlias {
         )?;
         let name = error!("TypeAlias", value);
 
+        if !matches!(scope, Scope::File) && matches!(value.first(), Some(Token::ParenClose | Token::Slash)) {
+            return Err(error!(
+                "TypeAlias",
+                Error::Other(format!("Associated type {name} can't have a visibility"))
+            ));
+        }
+
         if value.first() == Some(&Token::ParenClose) {
             value.pop_front();
             return Ok(Self::Def(name));
         }
 
-        let r#type = error!(Type::try_from(&mut *value), "TypeALias")?;
+        if value.first() == Some(&Token::Slash) {
+            let constraints = error!(Constraints::try_from(&mut *value), "TypeAlias")?;
+            let default = if value.first() == Some(&Token::ParenClose) {
+                None
+            } else {
+                Some(error!(Type::try_from(&mut *value), "TypeAlias")?)
+            };
+            let _ = error!("TypeAlias", value.pop_front(), [Token::ParenClose])?;
+
+            return Ok(Self::Associated {
+                name,
+                constraints,
+                default,
+            });
+        }
+
+        let mut params = error!(GenericParams::try_from(&mut *value), "TypeAlias")?;
+        let r#type = if value.first() == Some(&Token::Char('=')) {
+            value.pop_front();
+            error!(Type::try_from(&mut *value), "TypeALias")?
+        } else if params.is_empty() {
+            error!(Type::try_from(&mut *value), "TypeALias")?
+        } else if params.lifetimes.is_empty()
+            && params.generics.len() == 1
+            && value.first() == Some(&Token::ParenClose)
+        {
+            // Without an = there are no params, so the generic is the aliased type
+            Type::Generic(params.generics.pop().unwrap())
+        } else {
+            return Err(error!(
+                "TypeAlias",
+                Error::Other(format!("Expected = between the params of {name} and the aliased type"))
+            ));
+        };
         let _ = error!("TypeALias", value.pop_front(), [Token::ParenClose])?;
 
-        Ok(Self::Alias { name, r#type })
+        Ok(Self::Alias {
+            scope,
+            name,
+            params,
+            r#type,
+        })
     }
 }
 
 impl ToString for TypeAlias {
     fn to_string(&self) -> String {
         match self {
-            Self::Alias { name, r#type } => {
-                format!("type {} = {};", name, r#type.to_string())
-            }
+            Self::Alias {
+                scope,
+                name,
+                params,
+                r#type,
+            } => format!(
+                "{}type {name}{} = {};",
+                scope.to_string(),
+                params.to_string(),
+                r#type.to_string()
+            ),
             Self::Def(name) => format!("type {name};"),
+            Self::Associated {
+                name,
+                constraints,
+                default: Some(default),
+            } => format!(
+                "type {name}: {} = {};",
+                constraints.to_string(),
+                default.to_string()
+            ),
+            Self::Associated {
+                name,
+                constraints,
+                default: None,
+            } => format!("type {name}: {};", constraints.to_string()),
         }
     }
 }
@@ -1,29 +1,31 @@
 use crate::tokenizer::{Keywords, Token};
-use super::{error, Parser, ParserError, ParserErrorStack};
+use super::{defun::Scope, error, Parser, ParserError, ParserErrorStack};
 
 /// # language example
 /// (use std->collections->VecDeque)
 /// (use std->collections->(VecDeque HashMap HashSet))
 /// (use std->(collections->(VecDeque HashMap HashSet) env->args))
+/// (pub use parser->Parser)
 #[derive(Debug)]
-pub struct Use(UsePath);
+pub struct Use(Scope, UsePath);
 
 impl TryFrom<&mut Parser> for Use {
     type Error = ParserError;
 
     fn try_from(value: &mut Parser) -> Result<Self, Self::Error> {
         let _ = error!("Use", value.pop_front(), [Token::ParenOpen])?;
+        let scope = error!(Scope::try_from(&mut *value), "Use")?;
         let _ = error!("Use", value.pop_front(), [Token::Keyword(Keywords::Use)])?;
         let ret = error!(UsePath::try_from(&mut *value), "Use")?;
         let _ = error!("Use", value.pop_front(), [Token::ParenClose])?;
 
-        Ok(Self(ret))
+        Ok(Self(scope, ret))
     }
 }
 
 impl ToString for Use {
     fn to_string(&self) -> String {
-        format!("use {};", self.0.to_string())
+        format!("{}use {};", self.0.to_string(), self.1.to_string())
     }
 }
 
@@ -12,6 +12,7 @@ pub enum Token {
     Literal(Literals),
     Keyword(Keywords),
     Type(BuiltinTypes),
+    Lifetime(String),
     DoubleDot,
     Ref,
     Char(char),
@@ -27,6 +28,33 @@ pub enum Token {
     Identifier(String),
 }
 
+/// Back to the source form, for error messages
+impl ToString for Token {
+    fn to_string(&self) -> String {
+        match self {
+            Self::BackTick => "`".to_string(),
+            Self::Literal(literal) => literal.to_string(),
+            Self::Keyword(keyword) => keyword.to_string(),
+            Self::Type(BuiltinTypes::String) => "string".to_string(),
+            Self::Type(builtin) => builtin.to_string(),
+            Self::Lifetime(lifetime) => format!("'{lifetime}"),
+            Self::DoubleDot => "..".to_string(),
+            Self::Ref => "&".to_string(),
+            Self::Char(char) => format!("{char}"),
+            Self::Slash => "/".to_string(),
+            Self::ParenOpen => "(".to_string(),
+            Self::ParenClose => ")".to_string(),
+            Self::BracketOpen => "[".to_string(),
+            Self::BracketClose => "]".to_string(),
+            Self::AngleBracketOpen => "<".to_string(),
+            Self::AngleBracketClose => ">".to_string(),
+            Self::CurlyOpen => "{".to_string(),
+            Self::CurlyClose => "}".to_string(),
+            Self::Identifier(iden) => iden.to_string(),
+        }
+    }
+}
+
 impl From<Vec<Token>> for Tokens {
     fn from(value: Vec<Token>) -> Self {
         Tokens(value)
@@ -37,15 +65,28 @@ impl From<Vec<Token>> for Tokens {
 pub struct Int(pub bool, pub u128);
 
 impl Int {
-    fn digs(start: u128, chars: &mut Peekable<Chars>) -> u128 {
+    /// Reads a number starting with `first`, with an optional 0x, 0o or 0b prefix
+    fn number(first: char, chars: &mut Peekable<Chars>) -> u128 {
+        let radix = match (first, chars.peek()) {
+            ('0', Some('x')) => 16,
+            ('0', Some('o')) => 8,
+            ('0', Some('b')) => 2,
+            _ => return Self::digs(first.to_digit(10).unwrap() as u128, 10, chars),
+        };
+        chars.next();
+
+        Self::digs(0, radix, chars)
+    }
+
+    fn digs(start: u128, radix: u32, chars: &mut Peekable<Chars>) -> u128 {
         let mut num = start;
 
         loop {
             match chars.peek() {
-                Some(c) => match c.to_digit(10) {
+                Some(c) => match c.to_digit(radix) {
                     Some(dig) => {
                         chars.next();
-                        num = dig as u128 + num * 10
+                        num = dig as u128 + num * radix as u128
                     }
                     None => break num,
                 },
@@ -121,6 +162,47 @@ pub enum Keywords {
     BitwiseOr,
 }
 
+impl ToString for Keywords {
+    fn to_string(&self) -> String {
+        match self {
+            Self::TurboStart => "<",
+            Self::Type => "type",
+            Self::If => "if",
+            Self::Else => "else",
+            Self::Elif => "elif",
+            Self::Match => "match",
+            Self::Defun => "defun",
+            Self::Lambda => "lambda",
+            Self::Let => "let",
+            Self::Struct => "struct",
+            Self::Enum => "enum",
+            Self::Use => "use",
+            Self::For => "for",
+            Self::Loop => "loop",
+            Self::While => "while",
+            Self::Break => "break",
+            Self::Impl => "impl",
+            Self::Trait => "trait",
+            Self::Mod => "mod",
+            Self::As => "as",
+            Self::Continue => "continue",
+            Self::LeftArrow => "->",
+            Self::RightArrow => "<-",
+            Self::Return => "return",
+            Self::Do => "do",
+            Self::Mut => "mut",
+            Self::Deref => "*",
+            Self::Not => "not",
+            Self::And => "and",
+            Self::Or => "or",
+            Self::Xor => "xor",
+            Self::BitwiseAnd => "band",
+            Self::BitwiseOr => "bor",
+        }
+        .to_string()
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum BuiltinTypes {
     U8,
@@ -145,7 +227,7 @@ impl ToString for BuiltinTypes {
             BuiltinTypes::U16 => "u16",
             BuiltinTypes::U32 => "u32",
             BuiltinTypes::U64 => "u64",
-            BuiltinTypes::U128 => "k128",
+            BuiltinTypes::U128 => "u128",
             BuiltinTypes::I8 => "i8",
             BuiltinTypes::I16 => "i16",
             BuiltinTypes::I32 => "i32",
@@ -182,13 +264,26 @@ impl FromStr for Tokens {
                 '}' => tokens.push(Token::CurlyClose),
                 '/' => tokens.push(Token::Slash),
                 '\'' => {
-                    let ch = chars.next().unwrap();
+                    let ch = chars.next().ok_or("unfinished single quote")?;
+
+                    if chars.peek() == Some(&'\'') {
+                        chars.next();
+                        tokens.push(Token::Literal(Literals::Char(ch)));
+                    } else if ch.is_ascii_alphabetic() || ch == '_' {
+                        let mut label = vec![ch];
 
-                    if !matches!(chars.next(), Some('\'')) {
+                        while let Some(char) = chars.peek() {
+                            if !char.is_ascii_alphanumeric() && *char != '_' {
+                                break;
+                            }
+
+                            label.push(chars.next().unwrap());
+                        }
+
+                        tokens.push(Token::Lifetime(label.into_iter().collect()));
+                    } else {
                         return Err("unfinished closing single quote");
                     }
-
-                    tokens.push(Token::Literal(Literals::Char(ch)));
                 }
                 '"' => {
                     let mut literal = vec![];
@@ -221,7 +316,10 @@ impl FromStr for Tokens {
                     }
                 }
                 '-' if matches!(chars.peek(), Some(c) if c.is_ascii_digit()) => tokens.push(
-                    Token::Literal(Literals::Int(Int(true, Int::digs(0, &mut chars)))),
+                    Token::Literal(Literals::Int(Int(true, {
+                        let first = chars.next().unwrap();
+                        Int::number(first, &mut chars)
+                    }))),
                 ),
                 '-' if chars.peek() == Some(&'>') => {
                     chars.next();
@@ -232,7 +330,7 @@ impl FromStr for Tokens {
                 '`' => tokens.push(Token::BackTick),
                 char if char.is_ascii_digit() => tokens.push(Token::Literal(Literals::Int(Int(
                     false,
-                    Int::digs(char.to_digit(10).unwrap() as u128, &mut chars),
+                    Int::number(char, &mut chars),
                 )))),
                 char if char.is_ascii_alphanumeric() || char == '_' => {
                     let mut chs = vec![char];
@@ -242,8 +340,13 @@ impl FromStr for Tokens {
                             && *char != '-'
                             && *char != '_'
                             && *char != '!'
+                            && *char != '#'
                         {
                             break;
+                        } else if *char == '#' {
+                            // Gensyms in macro templates: tmp#
+                            chs.push(chars.next().unwrap());
+                            break;
                         } else if *char == '-' {
                             let ch = chars.next().unwrap();
                             match check_arrow(&mut chars)? {
@@ -399,4 +502,5 @@ mod tests {
     snapshot!(test_let, "../testdata/input/let.lt");
     snapshot!(test_struct, "../testdata/input/struct.lt");
     snapshot!(test_enum, "../testdata/input/enum.lt");
+    snapshot!(test_macro, "../testdata/input/macro.lt");
 }